@@ -3,6 +3,7 @@ use std::sync::{Arc, LazyLock, Mutex};
 use crate::{
     error::QueryError,
     pretty_dom::pretty_dom,
+    timers::DefaultClock,
     types::{Config, ConfigFnOrPartial},
 };
 
@@ -10,10 +11,16 @@ static CONFIG: LazyLock<Arc<Mutex<Config>>> = LazyLock::new(|| {
     Arc::new(Mutex::new(Config {
         test_id_attribute: "data-testid".to_owned(),
         event_wrapper: Arc::new(|cb| cb()),
+        async_wrapper: Arc::new(|cb| cb),
+        advance_timers_wrapper: Arc::new(|cb| cb),
+        clock: Arc::new(DefaultClock),
+        async_util_timeout: 1000,
         default_hidden: false,
         default_ignore: "script, style".to_owned(),
         show_original_stack_trace: false,
         throw_suggestions: false,
+        pierce_shadow_dom: false,
+        traverse_iframes: false,
         get_element_error: Arc::new(|message, container| {
             let prettified_dom = pretty_dom(Some(container.into()), None);
 
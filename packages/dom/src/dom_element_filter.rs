@@ -1,11 +1,15 @@
 use std::collections::HashMap;
 
+use js_sys::Reflect;
 use pretty_format::{Config, Plugin, Printer, Refs};
 use regex::Regex;
 use wasm_bindgen::{JsCast, JsValue};
-use web_sys::{Comment, Element, Node, Text};
+use web_sys::{Comment, Document, Element, Node, Text};
 
-use crate::util::{named_node_map_to_hashmap, named_node_map_to_vec, node_list_to_vec};
+use crate::{
+    traversal::{Traversal, get_frame_document, get_shadow_root, is_shadow_root},
+    util::{named_node_map_to_hashmap, named_node_map_to_vec, node_list_to_vec},
+};
 
 fn escape_html(text: String) -> String {
     text.replace('<', "&lt;").replace('>', "&gt;")
@@ -132,6 +136,10 @@ fn print_element(
     ))
 }
 
+fn print_boundary(name: &str, printed_children: String, config: &Config) -> String {
+    format!("{}{}", config.colors.tag.paint(name), printed_children)
+}
+
 fn print_element_as_leaf(r#type: String, config: &Config) -> String {
     let tag_color = &config.colors.tag;
     format!(
@@ -141,13 +149,22 @@ fn print_element_as_leaf(r#type: String, config: &Config) -> String {
     )
 }
 
+fn as_node(val: &JsValue) -> Option<&Node> {
+    // Nodes of same-origin frames belong to another realm, so `instanceof` based casts like `dyn_ref` fail for them.
+    Reflect::get(val, &"nodeType".into())
+        .is_ok_and(|node_type| node_type.as_f64().is_some())
+        .then(|| val.unchecked_ref::<Node>())
+}
+
 fn is_custom_element(val: &JsValue) -> bool {
-    val.dyn_ref::<Element>()
+    as_node(val)
+        .filter(|node| node.node_type() == Node::ELEMENT_NODE)
+        .map(|node| node.unchecked_ref::<Element>())
         .is_some_and(|element| element.tag_name().contains('-') || element.has_attribute("is"))
 }
 
 fn test_node(val: &JsValue) -> bool {
-    val.dyn_ref::<Node>().is_some_and(|node| {
+    as_node(val).is_some_and(|node| {
         let constructor_name: String = node.constructor().name().into();
         let node_type = node.node_type();
 
@@ -158,7 +175,10 @@ fn test_node(val: &JsValue) -> bool {
             || is_custom_element(val))
             || (node_type == Node::TEXT_NODE && constructor_name == "Text")
             || (node_type == Node::COMMENT_NODE && constructor_name == "Comment")
-            || (node_type == Node::DOCUMENT_FRAGMENT_NODE && constructor_name == "DocumentFragment")
+            || (node_type == Node::DOCUMENT_FRAGMENT_NODE
+                && (constructor_name == "DocumentFragment" || constructor_name == "ShadowRoot"))
+            || (node_type == Node::DOCUMENT_NODE
+                && (constructor_name == "Document" || constructor_name == "HTMLDocument"))
     })
 }
 
@@ -174,13 +194,21 @@ fn node_is_fragment(node: &Node) -> bool {
     node.node_type() == Node::DOCUMENT_FRAGMENT_NODE
 }
 
+fn node_is_document(node: &Node) -> bool {
+    node.node_type() == Node::DOCUMENT_NODE
+}
+
 pub struct DomElementFilter {
     filter_node: Box<dyn Fn(&Node) -> bool>,
+    traversal: Traversal,
 }
 
 impl DomElementFilter {
-    pub fn new(filter_node: Box<dyn Fn(&Node) -> bool>) -> Self {
-        Self { filter_node }
+    pub fn new(filter_node: Box<dyn Fn(&Node) -> bool>, traversal: Traversal) -> Self {
+        Self {
+            filter_node,
+            traversal,
+        }
     }
 }
 
@@ -208,6 +236,60 @@ impl Plugin for DomElementFilter {
             return print_comment(node.unchecked_ref::<Comment>().data(), config);
         }
 
+        if node_is_document(node) {
+            // Only the document element is printed, e.g. not the doctype.
+            let children = node
+                .unchecked_ref::<Document>()
+                .document_element()
+                .map(|element| vec![element.unchecked_into::<Node>()])
+                .unwrap_or_default();
+
+            return print_boundary(
+                "#document",
+                print_children(
+                    children,
+                    config,
+                    format!("{}{}", indentation, config.indent),
+                    depth,
+                    refs.clone(),
+                    printer,
+                ),
+                config,
+            );
+        }
+
+        // Open shadow roots are printed before the children of their host, as in the flat tree, if queries pierce them.
+        // The documents of same-origin frames are printed as children of the frame element, if queries traverse them.
+        let mut children = get_shadow_root(node)
+            .filter(|_| self.traversal.pierce_shadow_dom)
+            .map(|shadow_root| vec![shadow_root.unchecked_into::<Node>()])
+            .unwrap_or_default();
+        children.extend(
+            node_list_to_vec::<Node>(node.child_nodes())
+                .into_iter()
+                .filter(&self.filter_node),
+        );
+        children.extend(
+            get_frame_document(node)
+                .filter(|_| self.traversal.traverse_iframes)
+                .map(|document| document.unchecked_into::<Node>()),
+        );
+
+        if is_shadow_root(node) {
+            return print_boundary(
+                "#shadow-root",
+                print_children(
+                    children,
+                    config,
+                    format!("{}{}", indentation, config.indent),
+                    depth,
+                    refs.clone(),
+                    printer,
+                ),
+                config,
+            );
+        }
+
         let r#type = if node_is_fragment(node) {
             "DocumentFragment".to_owned()
         } else {
@@ -247,10 +329,7 @@ impl Plugin for DomElementFilter {
                 printer,
             ),
             print_children(
-                node_list_to_vec(node.child_nodes())
-                    .into_iter()
-                    .filter(&self.filter_node)
-                    .collect(),
+                children,
                 config,
                 format!("{}{}", indentation, config.indent),
                 depth,
@@ -11,6 +11,8 @@ pub enum QueryError {
     Element(String),
     #[error("{0}")]
     Unsupported(String),
+    #[error("aborted: {0:?}")]
+    Aborted(JsValue),
 }
 
 #[derive(Debug, Error, PartialEq)]
@@ -1,18 +1,23 @@
 use wasm_bindgen::JsCast;
-use web_sys::{Element, HtmlInputElement, Node};
+use web_sys::{HtmlInputElement, Node};
 
-use crate::util::node_list_to_vec;
+use crate::{
+    traversal::{as_element, get_flat_tree_child_nodes, is_slot},
+    util::node_list_to_vec,
+};
 
-pub fn get_node_text(node: &Node) -> String {
-    if node
-        .dyn_ref::<Element>()
+fn is_input_button(node: &Node) -> bool {
+    as_element(node)
         .map(|element| {
             element
                 .matches("input[type=submit], input[type=button], input[type=reset]")
                 .is_ok_and(|value| value)
         })
         .unwrap_or(false)
-    {
+}
+
+pub fn get_node_text(node: &Node) -> String {
+    if is_input_button(node) {
         node.unchecked_ref::<HtmlInputElement>().value()
     } else {
         node_list_to_vec::<Node>(node.child_nodes())
@@ -26,3 +31,26 @@ pub fn get_node_text(node: &Node) -> String {
             .join("")
     }
 }
+
+/// Same as [`get_node_text`], but in the flat tree: the text of a shadow host comes from its shadow root and slots are
+/// replaced by their assigned nodes.
+pub(crate) fn get_flat_tree_node_text(node: &Node) -> String {
+    if is_input_button(node) {
+        return node.unchecked_ref::<HtmlInputElement>().value();
+    }
+
+    get_flat_tree_child_nodes(node)
+        .into_iter()
+        .filter_map(|child| {
+            if child.node_type() == Node::TEXT_NODE {
+                child.text_content()
+            } else if is_slot(&child) {
+                // Slots do not generate boxes, so their content belongs to the parent.
+                Some(get_flat_tree_node_text(&child))
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("")
+}
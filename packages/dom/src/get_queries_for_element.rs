@@ -4,10 +4,19 @@ use crate::{
     ByRoleMatcher, ByRoleOptions,
     error::QueryError,
     queries::*,
-    types::{Matcher, MatcherOptions, SelectorMatcherOptions, WaitForOptions},
+    query_helpers::{
+        find_all_by_attribute, find_by_attribute, get_all_by_attribute, get_by_attribute,
+        query_all_by_attribute, query_by_attribute,
+    },
+    traversal::get_frame_body,
+    types::{ElementsOrCallback, Matcher, MatcherOptions, SelectorMatcherOptions, WaitForOptions},
+    wait_for_element_to_be_removed::wait_for_element_to_be_removed,
 };
 
 pub fn get_queries_for_element(element: HtmlElement) -> BoundQueries {
+    // Queries within a same-origin frame target the body of its document.
+    let element = get_frame_body(&element).unwrap_or(element);
+
     BoundQueries { element }
 }
 
@@ -15,6 +24,82 @@ pub struct BoundQueries {
     element: HtmlElement,
 }
 
+impl BoundQueries {
+    /// Element the queries are bound to.
+    pub fn element(&self) -> &HtmlElement {
+        &self.element
+    }
+
+    pub fn query_all_by_attribute<M: Into<Matcher>>(
+        &self,
+        attribute: &str,
+        matcher: M,
+        options: MatcherOptions,
+    ) -> Result<Vec<HtmlElement>, QueryError> {
+        query_all_by_attribute(attribute, &self.element, matcher, options)
+    }
+
+    pub fn query_by_attribute<M: Into<Matcher>>(
+        &self,
+        attribute: &str,
+        matcher: M,
+        options: MatcherOptions,
+    ) -> Result<Option<HtmlElement>, QueryError> {
+        query_by_attribute(attribute, &self.element, matcher, options)
+    }
+
+    pub fn get_all_by_attribute<M: Into<Matcher>>(
+        &self,
+        attribute: &str,
+        matcher: M,
+        options: MatcherOptions,
+    ) -> Result<Vec<HtmlElement>, QueryError> {
+        get_all_by_attribute(attribute, &self.element, matcher, options)
+    }
+
+    pub fn get_by_attribute<M: Into<Matcher>>(
+        &self,
+        attribute: &str,
+        matcher: M,
+        options: MatcherOptions,
+    ) -> Result<HtmlElement, QueryError> {
+        get_by_attribute(attribute, &self.element, matcher, options)
+    }
+
+    pub async fn find_all_by_attribute<M: Into<Matcher>>(
+        &self,
+        attribute: &str,
+        matcher: M,
+        options: MatcherOptions,
+        wait_for_options: WaitForOptions,
+    ) -> Result<Vec<HtmlElement>, QueryError> {
+        find_all_by_attribute(attribute, &self.element, matcher, options, wait_for_options).await
+    }
+
+    pub async fn find_by_attribute<M: Into<Matcher>>(
+        &self,
+        attribute: &str,
+        matcher: M,
+        options: MatcherOptions,
+        wait_for_options: WaitForOptions,
+    ) -> Result<HtmlElement, QueryError> {
+        find_by_attribute(attribute, &self.element, matcher, options, wait_for_options).await
+    }
+
+    pub async fn wait_for_element_to_be_removed<C: Into<ElementsOrCallback>>(
+        &self,
+        callback: C,
+        options: WaitForOptions,
+    ) -> Result<(), QueryError> {
+        let options = match options.container {
+            Some(_) => options,
+            None => options.container(self.element.clone()),
+        };
+
+        wait_for_element_to_be_removed(callback, options).await
+    }
+}
+
 macro_rules! queries_for_element {
     ($(($name:ident, $matcher_type:ty, $options_type:ty)),*,) => {
         paste::paste! {
@@ -83,3 +168,118 @@ queries_for_element!(
     (text, Matcher, SelectorMatcherOptions),
     (title, Matcher, MatcherOptions),
 );
+
+/// Defines a trait with bound versions of custom queries built with [`build_queries!`](crate::build_queries),
+/// implemented for [`BoundQueries`].
+///
+/// The query functions need to be in scope. Import the trait to call the queries on [`within`](crate::within) and
+/// [`screen`](crate::screen).
+///
+/// ```rust,ignore
+/// use testing_library_dom::{Matcher, MatcherOptions, bound_queries, screen};
+///
+/// bound_queries!(
+///     pub trait DataCyQueries,
+///     (data_cy, Matcher, MatcherOptions),
+/// );
+///
+/// screen().get_by_data_cy("submit", MatcherOptions::default())?;
+/// ```
+#[macro_export]
+macro_rules! bound_queries {
+    ($vis:vis trait $trait:ident, $(($name:ident, $matcher_type:ty, $options_type:ty)),* $(,)?) => {
+        $crate::paste::paste! {
+            $vis trait $trait {
+                $(fn [< find_by_ $name >]<M: Into<$matcher_type>>(
+                    &self,
+                    matcher: M,
+                    options: $options_type,
+                    wait_for_options: $crate::WaitForOptions,
+                ) -> impl ::std::future::Future<Output = Result<$crate::web_sys::HtmlElement, $crate::QueryError>>;)*
+
+                $(fn [< find_all_by_ $name >]<M: Into<$matcher_type>>(
+                    &self,
+                    matcher: M,
+                    options: $options_type,
+                    wait_for_options: $crate::WaitForOptions,
+                ) -> impl ::std::future::Future<Output = Result<Vec<$crate::web_sys::HtmlElement>, $crate::QueryError>>;)*
+
+                $(fn [< get_by_ $name >]<M: Into<$matcher_type>>(
+                    &self,
+                    matcher: M,
+                    options: $options_type,
+                ) -> Result<$crate::web_sys::HtmlElement, $crate::QueryError>;)*
+
+                $(fn [< get_all_by_ $name >]<M: Into<$matcher_type>>(
+                    &self,
+                    matcher: M,
+                    options: $options_type,
+                ) -> Result<Vec<$crate::web_sys::HtmlElement>, $crate::QueryError>;)*
+
+                $(fn [< query_by_ $name >]<M: Into<$matcher_type>>(
+                    &self,
+                    matcher: M,
+                    options: $options_type,
+                ) -> Result<Option<$crate::web_sys::HtmlElement>, $crate::QueryError>;)*
+
+                $(fn [< query_all_by_ $name >]<M: Into<$matcher_type>>(
+                    &self,
+                    matcher: M,
+                    options: $options_type,
+                ) -> Result<Vec<$crate::web_sys::HtmlElement>, $crate::QueryError>;)*
+            }
+
+            impl $trait for $crate::BoundQueries {
+                $(async fn [< find_by_ $name >]<M: Into<$matcher_type>>(
+                    &self,
+                    matcher: M,
+                    options: $options_type,
+                    wait_for_options: $crate::WaitForOptions,
+                ) -> Result<$crate::web_sys::HtmlElement, $crate::QueryError> {
+                    [< find_by_ $name >](self.element(), matcher, options, wait_for_options).await
+                })*
+
+                $(async fn [< find_all_by_ $name >]<M: Into<$matcher_type>>(
+                    &self,
+                    matcher: M,
+                    options: $options_type,
+                    wait_for_options: $crate::WaitForOptions,
+                ) -> Result<Vec<$crate::web_sys::HtmlElement>, $crate::QueryError> {
+                    [< find_all_by_ $name >](self.element(), matcher, options, wait_for_options).await
+                })*
+
+                $(fn [< get_by_ $name >]<M: Into<$matcher_type>>(
+                    &self,
+                    matcher: M,
+                    options: $options_type,
+                ) -> Result<$crate::web_sys::HtmlElement, $crate::QueryError> {
+                    [< get_by_ $name >](self.element(), matcher, options)
+                })*
+
+                $(fn [< get_all_by_ $name >]<M: Into<$matcher_type>>(
+                    &self,
+                    matcher: M,
+                    options: $options_type,
+                ) -> Result<Vec<$crate::web_sys::HtmlElement>, $crate::QueryError> {
+                    [< get_all_by_ $name >](self.element(), matcher, options)
+                })*
+
+                $(fn [< query_by_ $name >]<M: Into<$matcher_type>>(
+                    &self,
+                    matcher: M,
+                    options: $options_type,
+                ) -> Result<Option<$crate::web_sys::HtmlElement>, $crate::QueryError> {
+                    [< query_by_ $name >](self.element(), matcher, options)
+                })*
+
+                $(fn [< query_all_by_ $name >]<M: Into<$matcher_type>>(
+                    &self,
+                    matcher: M,
+                    options: $options_type,
+                ) -> Result<Vec<$crate::web_sys::HtmlElement>, $crate::QueryError> {
+                    [< query_all_by_ $name >](self.element(), matcher, options)
+                })*
+            }
+        }
+    };
+}
@@ -1,8 +1,7 @@
 use regex::Regex;
-use wasm_bindgen::JsCast;
-use web_sys::{Element, HtmlElement, HtmlInputElement, HtmlLabelElement, Node};
+use web_sys::{Element, HtmlElement, HtmlLabelElement, Node};
 
-use crate::util::node_list_to_vec;
+use crate::{traversal::as_input_element, util::node_list_to_vec};
 
 const LABELLED_NODE_NAMES: [&str; 7] = [
     "button", "meter", "output", "progress", "select", "textarea", "input",
@@ -28,15 +27,14 @@ pub fn get_label_content(element: &Element) -> Option<String> {
     if element.tag_name().to_lowercase() == "label" {
         get_text_content(element)
     } else {
-        element
-            .dyn_ref::<HtmlInputElement>()
+        as_input_element(element)
             .map(|input_element| input_element.value())
             .or(element.text_content())
     }
 }
 
 pub fn get_real_labels(element: &HtmlElement) -> Vec<HtmlLabelElement> {
-    if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+    if let Some(input) = as_input_element(element) {
         return input
             .labels()
             .map(node_list_to_vec::<HtmlLabelElement>)
@@ -13,9 +13,12 @@ pub mod query_helpers;
 mod role_helpers;
 mod screen;
 mod suggestions;
+mod timers;
+mod traversal;
 mod types;
 mod util;
 mod wait_for;
+mod wait_for_element_to_be_removed;
 
 pub use config::{configure, get_config};
 pub use error::QueryError;
@@ -33,8 +36,16 @@ pub use role_helpers::{
 };
 pub use screen::*;
 pub use suggestions::*;
+pub use timers::*;
 pub use types::*;
 pub use wait_for::*;
+pub use wait_for_element_to_be_removed::*;
+
+// Used by `build_queries!` and `bound_queries!`, so custom queries don't need to depend on them.
+#[doc(hidden)]
+pub use paste;
+#[doc(hidden)]
+pub use web_sys;
 
 // TODO: Export useful types from `aria_query`.
 #[doc(no_inline)]
@@ -4,7 +4,10 @@ use pretty_format::PrettyFormatOptions;
 use wasm_bindgen::{JsCast, JsValue};
 use web_sys::{Document, Element, HtmlElement, Node};
 
-use crate::{config::get_config, dom_element_filter::DomElementFilter, helpers::get_document};
+use crate::{
+    config::get_config, dom_element_filter::DomElementFilter, helpers::get_document,
+    traversal::Traversal,
+};
 
 pub enum DocumentOrElement {
     Document(Document),
@@ -74,7 +77,11 @@ pub fn pretty_dom(dom: Option<DocumentOrElement>, max_length: Option<usize>) ->
         &dom,
         // TODO: pass options
         PrettyFormatOptions::default()
-            .plugins(vec![Rc::new(DomElementFilter::new(Box::new(filter_node)))])
+            .plugins(vec![Rc::new(DomElementFilter::new(
+                Box::new(filter_node),
+                // Only print the boundaries which queries descend into.
+                Traversal::current(),
+            ))])
             .print_function_name(false)
             .highlight(should_highlight()),
     )
@@ -6,8 +6,9 @@ use crate::{
     error::QueryError,
     get_node_text::get_node_text,
     matches::{fuzzy_matches, make_normalizer, matches},
+    traversal::{Traversal, query_selector_all},
     types::{Matcher, MatcherOptions, NormalizerOptions},
-    util::{html_collection_to_vec, node_list_to_vec},
+    util::html_collection_to_vec,
 };
 
 pub(crate) fn _query_all_by_display_value<M: Into<Matcher>>(
@@ -16,6 +17,7 @@ pub(crate) fn _query_all_by_display_value<M: Into<Matcher>>(
     options: MatcherOptions,
 ) -> Result<Vec<HtmlElement>, QueryError> {
     let value = value.into();
+    let traversal = Traversal::new(options.pierce_shadow_dom, options.traverse_iframes);
     let matcher = match options.exact.unwrap_or(true) {
         true => matches,
         false => fuzzy_matches,
@@ -26,37 +28,35 @@ pub(crate) fn _query_all_by_display_value<M: Into<Matcher>>(
         normalizer: options.normalizer,
     })?;
 
-    Ok(node_list_to_vec::<HtmlElement>(
-        container
-            .query_selector_all("input,textarea,select")
-            .map_err(QueryError::JsError)?,
-    )
-    .into_iter()
-    .filter(|node| {
-        if node.tag_name() == "SELECT" {
-            html_collection_to_vec::<HtmlOptionElement>(
-                node.unchecked_ref::<HtmlSelectElement>().options().into(),
-            )
+    Ok(
+        query_selector_all::<HtmlElement>(container, "input,textarea,select", traversal)?
             .into_iter()
-            .filter(|option| option.selected())
-            .any(|option_node| {
-                matcher(
-                    Some(get_node_text(&option_node)),
-                    Some(&option_node),
-                    &value,
-                    match_normalizer.as_ref(),
-                )
+            .filter(|node| {
+                if node.tag_name() == "SELECT" {
+                    html_collection_to_vec::<HtmlOptionElement>(
+                        node.unchecked_ref::<HtmlSelectElement>().options().into(),
+                    )
+                    .into_iter()
+                    .filter(|option| option.selected())
+                    .any(|option_node| {
+                        matcher(
+                            Some(get_node_text(&option_node)),
+                            Some(&option_node),
+                            &value,
+                            match_normalizer.as_ref(),
+                        )
+                    })
+                } else {
+                    matcher(
+                        Some(node.unchecked_ref::<HtmlInputElement>().value()),
+                        Some(node),
+                        &value,
+                        match_normalizer.as_ref(),
+                    )
+                }
             })
-        } else {
-            matcher(
-                Some(node.unchecked_ref::<HtmlInputElement>().value()),
-                Some(node),
-                &value,
-                match_normalizer.as_ref(),
-            )
-        }
-    })
-    .collect())
+            .collect(),
+    )
 }
 
 fn get_multiple_error(
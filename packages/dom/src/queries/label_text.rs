@@ -6,8 +6,8 @@ use crate::{
     label_helpers::{get_labels, get_real_labels},
     matches::{fuzzy_matches, make_normalizer, matches},
     query_all_by_attribute,
+    traversal::{Traversal, query_selector_all},
     types::{Matcher, MatcherOptions, NormalizerOptions, SelectorMatcherOptions},
-    util::node_list_to_vec,
 };
 
 pub(crate) fn _query_all_by_label_text<M: Into<Matcher>>(
@@ -16,6 +16,7 @@ pub(crate) fn _query_all_by_label_text<M: Into<Matcher>>(
     options: SelectorMatcherOptions,
 ) -> Result<Vec<HtmlElement>, QueryError> {
     let text = text.into();
+    let traversal = Traversal::new(options.pierce_shadow_dom, options.traverse_iframes);
     let selector = options.selector.unwrap_or("*".to_owned());
     let matcher = match options.exact.unwrap_or(true) {
         true => matches,
@@ -27,49 +28,35 @@ pub(crate) fn _query_all_by_label_text<M: Into<Matcher>>(
         normalizer: options.normalizer,
     })?;
 
-    let mut matching_labelled_elements = node_list_to_vec::<HtmlElement>(
-        container
-            .query_selector_all("*")
-            .map_err(QueryError::JsError)?,
-    )
-    .into_iter()
-    .filter(|element| {
-        !get_real_labels(element).is_empty() || element.has_attribute("aria-labelledby")
-    })
-    .fold(vec![], |mut labelled_elements, labelled_element| {
-        let label_list = get_labels(container, &labelled_element, Some(selector.clone()));
-
-        for label in &label_list {
-            if let Some(form_control) = label.form_control.as_ref()
-                && matcher(
-                    label.content.clone(),
-                    label.form_control.as_deref(),
-                    &text,
-                    match_normalizer.as_ref(),
-                )
-            {
-                labelled_elements.push(form_control.clone());
-            }
-        }
-
-        let labels_value = label_list
+    let mut matching_labelled_elements =
+        query_selector_all::<HtmlElement>(container, "*", traversal)?
             .into_iter()
-            .filter_map(|label| label.content)
-            .collect::<Vec<_>>();
-
-        if matcher(
-            Some(labels_value.join(" ")),
-            Some(labelled_element.as_ref()),
-            &text,
-            match_normalizer.as_ref(),
-        ) {
-            labelled_elements.push(labelled_element.clone());
-        }
+            .filter(|element| {
+                !get_real_labels(element).is_empty() || element.has_attribute("aria-labelledby")
+            })
+            .fold(vec![], |mut labelled_elements, labelled_element| {
+                let label_list = get_labels(container, &labelled_element, Some(selector.clone()));
+
+                for label in &label_list {
+                    if let Some(form_control) = label.form_control.as_ref()
+                        && matcher(
+                            label.content.clone(),
+                            label.form_control.as_deref(),
+                            &text,
+                            match_normalizer.as_ref(),
+                        )
+                    {
+                        labelled_elements.push(form_control.clone());
+                    }
+                }
+
+                let labels_value = label_list
+                    .into_iter()
+                    .filter_map(|label| label.content)
+                    .collect::<Vec<_>>();
 
-        if labels_value.len() > 1 {
-            for (index, label_value) in labels_value.iter().enumerate() {
                 if matcher(
-                    Some(label_value.clone()),
+                    Some(labels_value.join(" ")),
                     Some(labelled_element.as_ref()),
                     &text,
                     match_normalizer.as_ref(),
@@ -77,26 +64,37 @@ pub(crate) fn _query_all_by_label_text<M: Into<Matcher>>(
                     labelled_elements.push(labelled_element.clone());
                 }
 
-                let labels_filtered = labels_value
-                    .clone()
-                    .splice(index..index + 1, vec![])
-                    .collect::<Vec<_>>();
-
-                if labels_filtered.len() > 1
-                    && matcher(
-                        Some(labels_filtered.join(" ")),
-                        Some(labelled_element.as_ref()),
-                        &text,
-                        match_normalizer.as_ref(),
-                    )
-                {
-                    labelled_elements.push(labelled_element.clone());
+                if labels_value.len() > 1 {
+                    for (index, label_value) in labels_value.iter().enumerate() {
+                        if matcher(
+                            Some(label_value.clone()),
+                            Some(labelled_element.as_ref()),
+                            &text,
+                            match_normalizer.as_ref(),
+                        ) {
+                            labelled_elements.push(labelled_element.clone());
+                        }
+
+                        let labels_filtered = labels_value
+                            .clone()
+                            .splice(index..index + 1, vec![])
+                            .collect::<Vec<_>>();
+
+                        if labels_filtered.len() > 1
+                            && matcher(
+                                Some(labels_filtered.join(" ")),
+                                Some(labelled_element.as_ref()),
+                                &text,
+                                match_normalizer.as_ref(),
+                            )
+                        {
+                            labelled_elements.push(labelled_element.clone());
+                        }
+                    }
                 }
-            }
-        }
 
-        labelled_elements
-    });
+                labelled_elements
+            });
 
     matching_labelled_elements.append(&mut query_all_by_attribute(
         "aria-label",
@@ -108,6 +106,8 @@ pub(crate) fn _query_all_by_label_text<M: Into<Matcher>>(
             trim: None,
             collapse_whitespace: None,
             suggest: None,
+            pierce_shadow_dom: Some(traversal.pierce_shadow_dom),
+            traverse_iframes: Some(traversal.traverse_iframes),
         },
     )?);
 
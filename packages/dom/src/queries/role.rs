@@ -4,7 +4,7 @@ use aria_query::{AriaProperty, AriaRole, ROLE_ELEMENTS, ROLES};
 use dom_accessibility_api::{
     ComputeTextAlternativeOptions, compute_accessible_description, compute_accessible_name,
 };
-use web_sys::HtmlElement;
+use web_sys::{Element, HtmlElement};
 
 use crate::{
     build_queries,
@@ -12,13 +12,16 @@ use crate::{
     error::QueryError,
     matches::matches,
     role_helpers::{
-        compute_aria_busy, compute_aria_checked, compute_aria_current, compute_aria_expanded,
-        compute_aria_pressed, compute_aria_selected, compute_aria_value_max,
-        compute_aria_value_min, compute_aria_value_now, compute_aria_value_text,
-        compute_heading_level, get_implicit_aria_roles, is_inaccessible,
+        PrettyRolesOptions, compute_aria_busy, compute_aria_checked, compute_aria_current,
+        compute_aria_disabled, compute_aria_expanded, compute_aria_haspopup, compute_aria_invalid,
+        compute_aria_level, compute_aria_multiselectable, compute_aria_posinset,
+        compute_aria_pressed, compute_aria_readonly, compute_aria_required, compute_aria_selected,
+        compute_aria_setsize, compute_aria_value_max, compute_aria_value_min,
+        compute_aria_value_now, compute_aria_value_text, get_implicit_aria_roles, is_inaccessible,
+        pretty_roles,
     },
-    types::{ByRoleMatcher, ByRoleOptions, Matcher},
-    util::node_list_to_vec,
+    traversal::{Traversal, get_child_elements, query_selector_all},
+    types::{ByRoleMatcher, ByRoleOptions, HasPopup, Matcher},
 };
 
 pub(crate) fn _query_all_by_role<M: Into<ByRoleMatcher>>(
@@ -27,12 +30,13 @@ pub(crate) fn _query_all_by_role<M: Into<ByRoleMatcher>>(
     options: ByRoleOptions,
 ) -> Result<Vec<HtmlElement>, QueryError> {
     let role = role.into();
-    let role_string = role.to_string();
+    let roles = role.roles()?;
 
     let hidden = options.hidden.unwrap_or(get_config().default_hidden);
     let name = options.name;
     let description = options.description;
     let query_fallbacks = options.query_fallbacks.unwrap_or(false);
+    let traversal = Traversal::new(options.pierce_shadow_dom, options.traverse_iframes);
     let selected = options.selected;
     let busy = options.busy;
     let checked = options.checked;
@@ -45,12 +49,18 @@ pub(crate) fn _query_all_by_role<M: Into<ByRoleMatcher>>(
     let value_min = options_value.min;
     let value_max = options_value.max;
     let value_text = options_value.text;
+    let disabled = options.disabled;
+    let invalid = options.invalid;
+    let required = options.required;
+    let readonly = options.readonly;
+    let multiselectable = options.multiselectable;
+    let haspopup = options.haspopup;
+    let position_in_set = options.position_in_set;
+    let set_size = options.set_size;
 
     // Guard against unknown roles.
     if selected.is_some()
-        && !ROLES
-            .get(&role.into())
-            .is_some_and(|role| role.props.contains_key(&AriaProperty::AriaSelected))
+        && let Some(role) = find_unsupported_role(&roles, AriaProperty::AriaSelected)
     {
         return Err(QueryError::Unsupported(format!(
             "\"aria-selected\" is not supported on role \"{role}\"."
@@ -59,9 +69,7 @@ pub(crate) fn _query_all_by_role<M: Into<ByRoleMatcher>>(
 
     // Guard against unknown roles.
     if busy.is_some()
-        && !ROLES
-            .get(&role.into())
-            .is_some_and(|role| role.props.contains_key(&AriaProperty::AriaBusy))
+        && let Some(role) = find_unsupported_role(&roles, AriaProperty::AriaBusy)
     {
         return Err(QueryError::Unsupported(format!(
             "\"aria-busy\" is not supported on role \"{role}\"."
@@ -70,9 +78,7 @@ pub(crate) fn _query_all_by_role<M: Into<ByRoleMatcher>>(
 
     // Guard against unknown roles.
     if checked.is_some()
-        && !ROLES
-            .get(&role.into())
-            .is_some_and(|role| role.props.contains_key(&AriaProperty::AriaChecked))
+        && let Some(role) = find_unsupported_role(&roles, AriaProperty::AriaChecked)
     {
         return Err(QueryError::Unsupported(format!(
             "\"aria-checked\" is not supported on role \"{role}\"."
@@ -81,9 +87,7 @@ pub(crate) fn _query_all_by_role<M: Into<ByRoleMatcher>>(
 
     // Guard against unknown roles.
     if pressed.is_some()
-        && !ROLES
-            .get(&role.into())
-            .is_some_and(|role| role.props.contains_key(&AriaProperty::AriaPressed))
+        && let Some(role) = find_unsupported_role(&roles, AriaProperty::AriaPressed)
     {
         return Err(QueryError::Unsupported(format!(
             "\"aria-pressed\" is not supported on role \"{role}\"."
@@ -94,17 +98,17 @@ pub(crate) fn _query_all_by_role<M: Into<ByRoleMatcher>>(
     // All currently released ARIA versions support `aria-current` on all roles.
     // Leaving this for symmetry and forward compatibility.
     if current.is_some()
-        && !ROLES
-            .get(&role.into())
-            .is_some_and(|role| role.props.contains_key(&AriaProperty::AriaCurrent))
+        && let Some(role) = find_unsupported_role(&roles, AriaProperty::AriaCurrent)
     {
         return Err(QueryError::Unsupported(format!(
             "\"aria-current\" is not supported on role \"{role}\"."
         )));
     }
 
-    // Guard against using `level` option with any role other than `heading`.
-    if level.is_some() && role != AriaRole::Heading {
+    // Guard against using `level` option with any role which does not support `aria-level`.
+    if level.is_some()
+        && let Some(role) = find_unsupported_role(&roles, AriaProperty::AriaLevel)
+    {
         return Err(QueryError::Unsupported(format!(
             "Role \"{role}\" cannot have \"level\" property."
         )));
@@ -112,9 +116,7 @@ pub(crate) fn _query_all_by_role<M: Into<ByRoleMatcher>>(
 
     // Guard against unknown roles.
     if value_now.is_some()
-        && !ROLES
-            .get(&role.into())
-            .is_some_and(|role| role.props.contains_key(&AriaProperty::AriaValuenow))
+        && let Some(role) = find_unsupported_role(&roles, AriaProperty::AriaValuenow)
     {
         return Err(QueryError::Unsupported(format!(
             "\"aria-valuenow\" is not supported on role \"{role}\"."
@@ -123,9 +125,7 @@ pub(crate) fn _query_all_by_role<M: Into<ByRoleMatcher>>(
 
     // Guard against unknown roles.
     if value_max.is_some()
-        && !ROLES
-            .get(&role.into())
-            .is_some_and(|role| role.props.contains_key(&AriaProperty::AriaValuemax))
+        && let Some(role) = find_unsupported_role(&roles, AriaProperty::AriaValuemax)
     {
         return Err(QueryError::Unsupported(format!(
             "\"aria-valuemax\" is not supported on role \"{role}\"."
@@ -134,9 +134,7 @@ pub(crate) fn _query_all_by_role<M: Into<ByRoleMatcher>>(
 
     // Guard against unknown roles.
     if value_min.is_some()
-        && !ROLES
-            .get(&role.into())
-            .is_some_and(|role| role.props.contains_key(&AriaProperty::AriaValuemin))
+        && let Some(role) = find_unsupported_role(&roles, AriaProperty::AriaValuemin)
     {
         return Err(QueryError::Unsupported(format!(
             "\"aria-valuemin\" is not supported on role \"{role}\"."
@@ -145,9 +143,7 @@ pub(crate) fn _query_all_by_role<M: Into<ByRoleMatcher>>(
 
     // Guard against unknown roles.
     if value_text.is_some()
-        && !ROLES
-            .get(&role.into())
-            .is_some_and(|role| role.props.contains_key(&AriaProperty::AriaValuetext))
+        && let Some(role) = find_unsupported_role(&roles, AriaProperty::AriaValuetext)
     {
         return Err(QueryError::Unsupported(format!(
             "\"aria-valuetext\" is not supported on role \"{role}\"."
@@ -156,23 +152,98 @@ pub(crate) fn _query_all_by_role<M: Into<ByRoleMatcher>>(
 
     // Guard against unknown roles.
     if expanded.is_some()
-        && !ROLES
-            .get(&role.into())
-            .is_some_and(|role| role.props.contains_key(&AriaProperty::AriaExpanded))
+        && let Some(role) = find_unsupported_role(&roles, AriaProperty::AriaExpanded)
     {
         return Err(QueryError::Unsupported(format!(
             "\"aria-expanded\" is not supported on role \"{role}\"."
         )));
     }
 
-    Ok(node_list_to_vec::<HtmlElement>(
-        container
-            .query_selector_all(
-                // Only query elements that can be matched by the following filters.
-                &make_role_selector(role),
-            )
-            .map_err(QueryError::JsError)?,
-    )
+    // Guard against unknown roles.
+    if disabled.is_some()
+        && let Some(role) = find_unsupported_role(&roles, AriaProperty::AriaDisabled)
+    {
+        return Err(QueryError::Unsupported(format!(
+            "\"aria-disabled\" is not supported on role \"{role}\"."
+        )));
+    }
+
+    // Guard against unknown roles.
+    if invalid.is_some()
+        && let Some(role) = find_unsupported_role(&roles, AriaProperty::AriaInvalid)
+    {
+        return Err(QueryError::Unsupported(format!(
+            "\"aria-invalid\" is not supported on role \"{role}\"."
+        )));
+    }
+
+    // Guard against unknown roles.
+    if required.is_some()
+        && let Some(role) = find_unsupported_role(&roles, AriaProperty::AriaRequired)
+    {
+        return Err(QueryError::Unsupported(format!(
+            "\"aria-required\" is not supported on role \"{role}\"."
+        )));
+    }
+
+    // Guard against unknown roles.
+    if readonly.is_some()
+        && let Some(role) = find_unsupported_role(&roles, AriaProperty::AriaReadonly)
+    {
+        return Err(QueryError::Unsupported(format!(
+            "\"aria-readonly\" is not supported on role \"{role}\"."
+        )));
+    }
+
+    // Guard against unknown roles.
+    if multiselectable.is_some()
+        && let Some(role) = find_unsupported_role(&roles, AriaProperty::AriaMultiselectable)
+    {
+        return Err(QueryError::Unsupported(format!(
+            "\"aria-multiselectable\" is not supported on role \"{role}\"."
+        )));
+    }
+
+    // Guard against unknown roles.
+    if haspopup.is_some()
+        && let Some(role) = find_unsupported_role(&roles, AriaProperty::AriaHaspopup)
+    {
+        return Err(QueryError::Unsupported(format!(
+            "\"aria-haspopup\" is not supported on role \"{role}\"."
+        )));
+    }
+
+    // Guard against unknown roles.
+    if position_in_set.is_some()
+        && let Some(role) = find_unsupported_role(&roles, AriaProperty::AriaPosinset)
+    {
+        return Err(QueryError::Unsupported(format!(
+            "\"aria-posinset\" is not supported on role \"{role}\"."
+        )));
+    }
+
+    // Guard against unknown roles.
+    if set_size.is_some()
+        && let Some(role) = find_unsupported_role(&roles, AriaProperty::AriaSetsize)
+    {
+        return Err(QueryError::Unsupported(format!(
+            "\"aria-setsize\" is not supported on role \"{role}\"."
+        )));
+    }
+
+    let role_matches = |aria_role: AriaRole, element: &Element| match &role {
+        ByRoleMatcher::Function(function) => function(aria_role, element),
+        _ => roles
+            .as_ref()
+            .is_some_and(|roles| roles.contains(&aria_role)),
+    };
+
+    Ok(query_selector_all::<HtmlElement>(
+        container,
+        // Only query elements that can be matched by the following filters.
+        &make_role_selector(&roles),
+        traversal,
+    )?
     .into_iter()
     .filter(|node| {
         if let Some(role_value) = node.get_attribute("role") {
@@ -180,15 +251,21 @@ pub(crate) fn _query_all_by_role<M: Into<ByRoleMatcher>>(
                 return role_value
                     .split(' ')
                     .filter(|role_attribute_token| !role_attribute_token.is_empty())
-                    .any(|role_attribute_token| role_attribute_token == role_string);
+                    .filter_map(|role_attribute_token| {
+                        role_attribute_token.parse::<AriaRole>().ok()
+                    })
+                    .any(|role_attribute_token| role_matches(role_attribute_token, node));
             }
 
             // Other wise only send the first token to match.
             return role_value
                 .split(' ')
                 .next()
+                .and_then(|first_role_attribute_token| {
+                    first_role_attribute_token.parse::<AriaRole>().ok()
+                })
                 .is_some_and(|first_role_attribute_token| {
-                    first_role_attribute_token == role_string
+                    role_matches(first_role_attribute_token, node)
                 });
         }
 
@@ -196,59 +273,91 @@ pub(crate) fn _query_all_by_role<M: Into<ByRoleMatcher>>(
 
         implicit_roles
             .into_iter()
-            .any(|implicit_role| implicit_role == role.into())
+            .filter_map(|implicit_role| implicit_role.to_string().parse::<AriaRole>().ok())
+            .any(|implicit_role| role_matches(implicit_role, node))
     })
     .filter(|element| {
-        if selected.is_some() {
-            return selected == compute_aria_selected(element);
+        // All specified ARIA attributes have to match, unspecified ones are ignored.
+        if selected.is_some() && selected != compute_aria_selected(element) {
+            return false;
+        }
+        if busy.is_some_and(|busy| busy != compute_aria_busy(element)) {
+            return false;
+        }
+        if checked.is_some() && checked != compute_aria_checked(element) {
+            return false;
         }
-        if let Some(busy) = busy {
-            return busy == compute_aria_busy(element);
+        if pressed.is_some() && pressed != compute_aria_pressed(element) {
+            return false;
+        }
+        if current
+            .as_ref()
+            .is_some_and(|current| *current != compute_aria_current(element))
+        {
+            return false;
         }
-        if checked.is_some() {
-            return checked == compute_aria_checked(element);
+        if expanded.is_some() && expanded != compute_aria_expanded(element) {
+            return false;
         }
-        if pressed.is_some() {
-            return pressed == compute_aria_pressed(element);
+        if level.is_some() && level != compute_aria_level(element) {
+            return false;
         }
-        if let Some(current) = &current {
-            return *current == compute_aria_current(element);
+        if value_now.is_some() && value_now != compute_aria_value_now(element) {
+            return false;
         }
-        if expanded.is_some() {
-            return expanded == compute_aria_expanded(element);
+        if value_max.is_some() && value_max != compute_aria_value_max(element) {
+            return false;
         }
-        if level.is_some() {
-            return level == compute_heading_level(element);
+        if value_min.is_some() && value_min != compute_aria_value_min(element) {
+            return false;
         }
-        if value_now.is_some() || value_max.is_some() || value_min.is_some() || value_text.is_some()
+        if disabled.is_some_and(|disabled| disabled != compute_aria_disabled(element)) {
+            return false;
+        }
+        if invalid.is_some_and(|invalid| invalid != compute_aria_invalid(element)) {
+            return false;
+        }
+        if required.is_some_and(|required| required != compute_aria_required(element)) {
+            return false;
+        }
+        if readonly.is_some_and(|readonly| readonly != compute_aria_readonly(element)) {
+            return false;
+        }
+        if multiselectable
+            .is_some_and(|multiselectable| multiselectable != compute_aria_multiselectable(element))
         {
-            let mut value_matches = true;
-
-            if value_now.is_some() {
-                value_matches = value_matches && value_now == compute_aria_value_now(element);
-            }
-            if value_max.is_some() {
-                value_matches = value_matches && value_max == compute_aria_value_max(element);
-            }
-            if value_min.is_some() {
-                value_matches = value_matches && value_min == compute_aria_value_min(element);
-            }
-            if let Some(value_text) = &value_text {
-                let normalizer = |text| text;
-
-                value_matches = value_matches
-                    && matches(
-                        compute_aria_value_text(element),
-                        Some(element),
-                        value_text,
-                        &normalizer,
-                    );
+            return false;
+        }
+        if let Some(haspopup) = haspopup {
+            let computed_haspopup = compute_aria_haspopup(element);
+
+            let haspopup_matches = match haspopup {
+                HasPopup::True => computed_haspopup != HasPopup::False,
+                haspopup => haspopup == computed_haspopup,
+            };
+            if !haspopup_matches {
+                return false;
             }
+        }
+        if position_in_set.is_some() && position_in_set != compute_aria_posinset(element) {
+            return false;
+        }
+        if set_size.is_some() && set_size != compute_aria_setsize(element) {
+            return false;
+        }
+        if let Some(value_text) = &value_text {
+            let normalizer = |text| text;
 
-            return value_matches;
+            if !matches(
+                compute_aria_value_text(element),
+                Some(element),
+                value_text,
+                &normalizer,
+            ) {
+                return false;
+            }
         }
 
-        // Don't care if ARIA attributes are unspecified.
         true
     })
     .filter(|element| {
@@ -291,36 +400,55 @@ pub(crate) fn _query_all_by_role<M: Into<ByRoleMatcher>>(
     .collect())
 }
 
-fn make_role_selector(role: ByRoleMatcher) -> String {
-    let explicit_role_selector = format!("*[role~=\"{role}\"]");
+fn find_unsupported_role(
+    roles: &Option<Vec<AriaRole>>,
+    property: AriaProperty,
+) -> Option<AriaRole> {
+    roles.iter().flatten().copied().find(|role| {
+        !ROLES
+            .get(&(*role).into())
+            .is_some_and(|role| role.props.contains_key(&property))
+    })
+}
 
-    let role_relations = ROLE_ELEMENTS.get(&role.into());
-    let implicit_role_selectors = role_relations.map(|role_relations| {
-        role_relations
-            .iter()
-            .map(|relation| relation.name.clone())
-            .collect::<HashSet<String>>()
-    });
+fn make_role_selector(roles: &Option<Vec<AriaRole>>) -> String {
+    // Any element can have a role matched by a function.
+    let Some(roles) = roles else {
+        return "*".to_owned();
+    };
 
-    let mut selectors = vec![explicit_role_selector];
+    let explicit_role_selectors = roles.iter().map(|role| format!("*[role~=\"{role}\"]"));
 
-    if let Some(implicit_role_selectors) = implicit_role_selectors {
-        selectors.extend(implicit_role_selectors);
-    }
+    let implicit_role_selectors = roles
+        .iter()
+        .filter_map(|role| ROLE_ELEMENTS.get(&(*role).into()))
+        .flat_map(|role_relations| role_relations.iter().map(|relation| relation.name.clone()))
+        .collect::<HashSet<String>>();
 
-    selectors.join(",")
+    explicit_role_selectors
+        .chain(implicit_role_selectors)
+        .collect::<Vec<_>>()
+        .join(",")
 }
 
-fn get_name_hint(name: Option<Matcher>) -> String {
-    match name {
-        Some(Matcher::String(name)) => format!(" and name \"{name}\""),
-        Some(Matcher::Regex(name)) => format!(" and name `{name}`"),
-        Some(Matcher::Number(name)) => format!(" and name `{name}`"),
-        Some(Matcher::Function(_name)) => " and name `Fn`".to_owned(),
+fn get_matcher_hint(kind: &str, matcher: Option<Matcher>) -> String {
+    match matcher {
+        Some(Matcher::String(matcher)) => format!(" and {kind} \"{matcher}\""),
+        Some(Matcher::Regex(matcher)) => format!(" and {kind} `{matcher}`"),
+        Some(Matcher::Number(matcher)) => format!(" and {kind} `{matcher}`"),
+        Some(Matcher::Function(_matcher)) => format!(" and {kind} `Fn`"),
         None => "".to_owned(),
     }
 }
 
+fn get_name_hint(name: Option<Matcher>) -> String {
+    get_matcher_hint("name", name)
+}
+
+fn get_description_hint(description: Option<Matcher>) -> String {
+    get_matcher_hint("description", description)
+}
+
 fn get_multiple_error(
     _container: &HtmlElement,
     role: ByRoleMatcher,
@@ -334,16 +462,48 @@ fn get_multiple_error(
 }
 
 fn get_missing_error(
-    _container: &HtmlElement,
+    container: &HtmlElement,
     role: ByRoleMatcher,
     options: ByRoleOptions,
 ) -> Result<String, QueryError> {
     let hidden = options.hidden.unwrap_or(get_config().default_hidden);
 
-    let name_hint = "";
-    let description_hint = "";
-    let role_message = "";
-    // TODO
+    let traversal = Traversal::new(options.pierce_shadow_dom, options.traverse_iframes);
+
+    let roles = get_child_elements(container, traversal)
+        .into_iter()
+        .map(|child_element| {
+            pretty_roles(
+                child_element,
+                PrettyRolesOptions {
+                    hidden: Some(hidden),
+                    include_description: Some(options.description.is_some()),
+                    pierce_shadow_dom: Some(traversal.pierce_shadow_dom),
+                    traverse_iframes: Some(traversal.traverse_iframes),
+                },
+            )
+        })
+        .collect::<String>();
+
+    let role_message = if roles.is_empty() {
+        if hidden {
+            "There are no available roles.".to_owned()
+        } else {
+            "There are no accessible roles. But there might be some inaccessible roles. \
+            If you wish to access them, then set the `hidden` option to `true`. \
+            Learn more about this here: https://testing-library.com/docs/dom-testing-library/api-queries#byrole"
+                .to_owned()
+        }
+    } else {
+        format!(
+            "Here are the {} roles:\n\n  {}",
+            match hidden {
+                true => "available",
+                false => "accessible",
+            },
+            roles.replace('\n', "\n  ").replace("\n  \n", "\n\n")
+        )
+    };
 
     Ok(format!(
         "Unable to find an {}element with the role \"{}\"{}{}\n\n{}",
@@ -352,8 +512,8 @@ fn get_missing_error(
             false => "accessible ",
         },
         role,
-        name_hint,
-        description_hint,
+        get_name_hint(options.name),
+        get_description_hint(options.description),
         role_message.trim()
     ))
 }
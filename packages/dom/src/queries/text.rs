@@ -4,10 +4,10 @@ use crate::{
     build_queries,
     config::get_config,
     error::QueryError,
-    get_node_text::get_node_text,
+    get_node_text::{get_flat_tree_node_text, get_node_text},
     matches::{fuzzy_matches, make_normalizer, matches},
+    traversal::{Traversal, is_slot, query_selector_all},
     types::{Ignore, Matcher, NormalizerOptions, SelectorMatcherOptions},
-    util::node_list_to_vec,
 };
 
 pub(crate) fn _query_all_by_text<M: Into<Matcher>>(
@@ -16,6 +16,7 @@ pub(crate) fn _query_all_by_text<M: Into<Matcher>>(
     options: SelectorMatcherOptions,
 ) -> Result<Vec<HtmlElement>, QueryError> {
     let text = text.into();
+    let traversal = Traversal::new(options.pierce_shadow_dom, options.traverse_iframes);
     let selector = options.selector.unwrap_or("*".to_owned());
     let ignore = options.ignore.unwrap_or(get_config().default_ignore.into());
     let matcher = match options.exact.unwrap_or(true) {
@@ -35,18 +36,21 @@ pub(crate) fn _query_all_by_text<M: Into<Matcher>>(
 
     Ok(base_array
         .into_iter()
-        .chain(node_list_to_vec::<HtmlElement>(
-            container
-                .query_selector_all(&selector)
-                .map_err(QueryError::JsError)?,
-        ))
+        .chain(query_selector_all::<HtmlElement>(
+            container, &selector, traversal,
+        )?)
         .filter(|node| match &ignore {
             Ignore::False => true,
             Ignore::String(ignore) => !node.matches(ignore).unwrap_or(false),
         })
+        // The text of slots is attributed to their parent in the flat tree.
+        .filter(|node| !traversal.pierce_shadow_dom || !is_slot(node))
         .filter(|node| {
             matcher(
-                Some(get_node_text(node)),
+                Some(match traversal.pierce_shadow_dom {
+                    true => get_flat_tree_node_text(node),
+                    false => get_node_text(node),
+                }),
                 Some(node),
                 &text,
                 match_normalizer.as_ref(),
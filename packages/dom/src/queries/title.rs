@@ -1,20 +1,20 @@
-use wasm_bindgen::JsCast;
-use web_sys::{Element, HtmlElement};
+use web_sys::HtmlElement;
 
 use crate::{
     build_queries,
     error::QueryError,
     get_node_text::get_node_text,
     matches::{fuzzy_matches, make_normalizer, matches},
+    traversal::{Traversal, as_element, query_selector_all},
     types::{Matcher, MatcherOptions, NormalizerOptions},
-    util::node_list_to_vec,
 };
 
 fn is_svg_title(node: &HtmlElement) -> bool {
     node.tag_name().to_lowercase() == "title"
         && node
             .parent_node()
-            .and_then(|parent_node| parent_node.dyn_into::<Element>().ok())
+            .as_ref()
+            .and_then(as_element)
             .is_some_and(|parent_node| parent_node.tag_name().to_lowercase() == "svg")
 }
 
@@ -24,6 +24,7 @@ pub(crate) fn _query_all_by_title<M: Into<Matcher>>(
     options: MatcherOptions,
 ) -> Result<Vec<HtmlElement>, QueryError> {
     let text = text.into();
+    let traversal = Traversal::new(options.pierce_shadow_dom, options.traverse_iframes);
     let matcher = match options.exact.unwrap_or(true) {
         true => matches,
         false => fuzzy_matches,
@@ -34,27 +35,25 @@ pub(crate) fn _query_all_by_title<M: Into<Matcher>>(
         normalizer: options.normalizer,
     })?;
 
-    Ok(node_list_to_vec::<HtmlElement>(
-        container
-            .query_selector_all("[title], svg > title")
-            .map_err(QueryError::JsError)?,
+    Ok(
+        query_selector_all::<HtmlElement>(container, "[title], svg > title", traversal)?
+            .into_iter()
+            .filter(|node| {
+                matcher(
+                    node.get_attribute("title"),
+                    Some(node),
+                    &text,
+                    match_normalizer.as_ref(),
+                ) || (is_svg_title(node)
+                    && matcher(
+                        Some(get_node_text(node)),
+                        Some(node),
+                        &text,
+                        match_normalizer.as_ref(),
+                    ))
+            })
+            .collect(),
     )
-    .into_iter()
-    .filter(|node| {
-        matcher(
-            node.get_attribute("title"),
-            Some(node),
-            &text,
-            match_normalizer.as_ref(),
-        ) || (is_svg_title(node)
-            && matcher(
-                Some(get_node_text(node)),
-                Some(node),
-                &text,
-                match_normalizer.as_ref(),
-            ))
-    })
-    .collect())
 }
 
 fn get_multiple_error(
@@ -1,17 +1,31 @@
+use std::rc::Rc;
+
 use web_sys::{Element, HtmlElement};
 
 use crate::{
-    Matcher, MatcherOptions, NormalizerOptions,
+    Matcher, MatcherOptions, NormalizerOptions, WaitForOptions,
     config::get_config,
     error::QueryError,
     matches::{fuzzy_matches, make_normalizer, matches},
-    util::node_list_to_vec,
+    traversal::{Traversal, query_selector_all},
+    wait_for::wait_for,
 };
 
 pub fn get_element_error(message: Option<String>, container: Element) -> QueryError {
     (get_config().get_element_error)(message, container)
 }
 
+/// Runs `f` with the traversal of a query, so the DOM printed in its errors includes the shadow roots and frames it
+/// descends into.
+#[doc(hidden)]
+pub fn with_query_traversal<T>(
+    pierce_shadow_dom: Option<bool>,
+    traverse_iframes: Option<bool>,
+    f: impl FnOnce() -> T,
+) -> T {
+    Traversal::new(pierce_shadow_dom, traverse_iframes).scope(f)
+}
+
 pub fn get_multiple_elements_found_error(message: String, container: Element) -> QueryError {
     get_element_error(
         Some(format!(
@@ -30,11 +44,14 @@ pub fn query_all_by_attribute<M: Into<Matcher>>(
         trim,
         collapse_whitespace,
         normalizer,
+        pierce_shadow_dom,
+        traverse_iframes,
         ..
     }: MatcherOptions,
 ) -> Result<Vec<HtmlElement>, QueryError> {
     let text = text.into();
     let exact = exact.unwrap_or(true);
+    let traversal = Traversal::new(pierce_shadow_dom, traverse_iframes);
 
     let matcher = match exact {
         true => matches,
@@ -46,21 +63,19 @@ pub fn query_all_by_attribute<M: Into<Matcher>>(
         normalizer,
     })?;
 
-    Ok(node_list_to_vec::<HtmlElement>(
-        container
-            .query_selector_all(&format!("[{attribute}]"))
-            .map_err(QueryError::JsError)?,
+    Ok(
+        query_selector_all::<HtmlElement>(container, &format!("[{attribute}]"), traversal)?
+            .into_iter()
+            .filter(|node| {
+                matcher(
+                    node.get_attribute(attribute),
+                    Some(node),
+                    &text,
+                    match_normalizer.as_ref(),
+                )
+            })
+            .collect(),
     )
-    .into_iter()
-    .filter(|node| {
-        matcher(
-            node.get_attribute(attribute),
-            Some(node),
-            &text,
-            match_normalizer.as_ref(),
-        )
-    })
-    .collect())
 }
 
 pub fn query_by_attribute<M: Into<Matcher>>(
@@ -71,17 +86,128 @@ pub fn query_by_attribute<M: Into<Matcher>>(
 ) -> Result<Option<HtmlElement>, QueryError> {
     let text = text.into();
 
-    let mut els = query_all_by_attribute(attribute, container, text.clone(), options)?;
-    if els.len() > 1 {
-        Err(get_multiple_elements_found_error(
-            format!("Found multiple elements by [{attribute}={text}]"),
-            container.clone().into(),
-        ))
-    } else {
-        Ok(els.pop())
+    with_query_traversal(options.pierce_shadow_dom, options.traverse_iframes, || {
+        let mut els = query_all_by_attribute(attribute, container, text.clone(), options)?;
+        if els.len() > 1 {
+            Err(get_multiple_elements_found_error(
+                format!("Found multiple elements by [{attribute}={text}]"),
+                container.clone().into(),
+            ))
+        } else {
+            Ok(els.pop())
+        }
+    })
+}
+
+pub fn get_all_by_attribute<M: Into<Matcher>>(
+    attribute: &str,
+    container: &HtmlElement,
+    text: M,
+    options: MatcherOptions,
+) -> Result<Vec<HtmlElement>, QueryError> {
+    let text = text.into();
+
+    with_query_traversal(options.pierce_shadow_dom, options.traverse_iframes, || {
+        let els = query_all_by_attribute(attribute, container, text.clone(), options)?;
+        if els.is_empty() {
+            Err(get_element_error(
+                Some(format!(
+                    "Unable to find an element by: [{attribute}=\"{text}\"]"
+                )),
+                container.clone().into(),
+            ))
+        } else {
+            Ok(els)
+        }
+    })
+}
+
+pub fn get_by_attribute<M: Into<Matcher>>(
+    attribute: &str,
+    container: &HtmlElement,
+    text: M,
+    options: MatcherOptions,
+) -> Result<HtmlElement, QueryError> {
+    let text = text.into();
+
+    with_query_traversal(options.pierce_shadow_dom, options.traverse_iframes, || {
+        let mut els = get_all_by_attribute(attribute, container, text.clone(), options)?;
+        if els.len() > 1 {
+            let element_strings = els
+                .into_iter()
+                .map(|element| format!("{}", get_element_error(None, element.into())))
+                .collect::<Vec<_>>()
+                .join("\n\n");
+
+            Err(get_multiple_elements_found_error(
+                format!(
+                    "Found multiple elements by: [{attribute}=\"{text}\"]\n\nHere are the matching elements:\n\n{element_strings}"
+                ),
+                container.clone().into(),
+            ))
+        } else {
+            Ok(els.swap_remove(0))
+        }
+    })
+}
+
+/// Options for the `wait_for` of `find` queries, observing the container. Errors of `get` queries already include the
+/// DOM, so unless `on_timeout` is set, the last error is returned unchanged on timeout.
+#[doc(hidden)]
+pub fn find_wait_for_options(
+    wait_for_options: WaitForOptions,
+    container: &HtmlElement,
+) -> WaitForOptions {
+    let wait_for_options = wait_for_options.container(container.clone());
+
+    match wait_for_options.on_timeout {
+        Some(_) => wait_for_options,
+        None => wait_for_options.on_timeout(Rc::new(|error| error)),
     }
 }
 
+pub async fn find_all_by_attribute<M: Into<Matcher>>(
+    attribute: &str,
+    container: &HtmlElement,
+    text: M,
+    options: MatcherOptions,
+    wait_for_options: WaitForOptions,
+) -> Result<Vec<HtmlElement>, QueryError> {
+    wait_for(
+        {
+            let attribute = attribute.to_owned();
+            let container = container.clone();
+            let text = text.into();
+            Box::new(move || {
+                get_all_by_attribute(&attribute, &container, text.clone(), options.clone())
+            })
+        },
+        find_wait_for_options(wait_for_options, container),
+    )
+    .await
+}
+
+pub async fn find_by_attribute<M: Into<Matcher>>(
+    attribute: &str,
+    container: &HtmlElement,
+    text: M,
+    options: MatcherOptions,
+    wait_for_options: WaitForOptions,
+) -> Result<HtmlElement, QueryError> {
+    wait_for(
+        {
+            let attribute = attribute.to_owned();
+            let container = container.clone();
+            let text = text.into();
+            Box::new(move || {
+                get_by_attribute(&attribute, &container, text.clone(), options.clone())
+            })
+        },
+        find_wait_for_options(wait_for_options, container),
+    )
+    .await
+}
+
 pub fn get_suggestion_error(suggestion: String, container: Element) -> QueryError {
     (get_config().get_element_error)(
         Some(format!(
@@ -101,25 +227,27 @@ macro_rules! make_single_query {
         ) -> Result<$return_type, QueryError> {
             let matcher = matcher.into();
 
-            let mut els = $all_query(container, matcher.clone(), options.clone())?;
-            if els.len() > 1 {
-                let element_strings = els
-                    .into_iter()
-                    .map(|element| format!("{}", get_element_error(None, element.into())))
-                    .collect::<Vec<_>>()
-                    .join("\n\n");
-
-                Err(get_multiple_elements_found_error(
-                    format!(
-                        "{}\n\nHere are the matching elements:\n\n{}",
-                        $get_multiple_error(container, matcher, options)?,
-                        element_strings
-                    ),
-                    container.clone().into(),
-                ))
-            } else {
-                Ok(Vec::$func(&mut els $(,$args)*))
-            }
+            $crate::query_helpers::with_query_traversal(options.pierce_shadow_dom, options.traverse_iframes, || {
+                let mut els = $all_query(container, matcher.clone(), options.clone())?;
+                if els.len() > 1 {
+                    let element_strings = els
+                        .into_iter()
+                        .map(|element| format!("{}", get_element_error(None, element.into())))
+                        .collect::<Vec<_>>()
+                        .join("\n\n");
+
+                    Err(get_multiple_elements_found_error(
+                        format!(
+                            "{}\n\nHere are the matching elements:\n\n{}",
+                            $get_multiple_error(container, matcher, options)?,
+                            element_strings
+                        ),
+                        container.clone().into(),
+                    ))
+                } else {
+                    Ok(Vec::$func(&mut els $(,$args)*))
+                }
+            })
         }
     };
 }
@@ -134,15 +262,21 @@ macro_rules! make_get_all_query {
         ) -> Result<Vec<HtmlElement>, QueryError> {
             let matcher = matcher.into();
 
-            let els = $all_query(container, matcher.clone(), options.clone())?;
-            if els.is_empty() {
-                return Err((get_config().get_element_error)(
-                    Some($get_missing_error(container, matcher, options)?),
-                    container.clone().into(),
-                ));
-            } else {
-                Ok(els)
-            }
+            $crate::query_helpers::with_query_traversal(
+                options.pierce_shadow_dom,
+                options.traverse_iframes,
+                || {
+                    let els = $all_query(container, matcher.clone(), options.clone())?;
+                    if els.is_empty() {
+                        Err((get_config().get_element_error)(
+                            Some($get_missing_error(container, matcher, options)?),
+                            container.clone().into(),
+                        ))
+                    } else {
+                        Ok(els)
+                    }
+                },
+            )
         }
     };
 }
@@ -162,7 +296,7 @@ macro_rules! make_find_query {
                     let container = container.clone();
                     Box::new(move || $getter(&container, matcher.clone(), options.clone()))
                 },
-                wait_for_options.container(container.clone()),
+                $crate::query_helpers::find_wait_for_options(wait_for_options, container),
             )
             .await
         }
@@ -177,17 +311,25 @@ macro_rules! wrap_single_query_with_suggestion {
             matcher: M,
             options: $options_type,
         ) -> Result<$return_type, QueryError> {
+            let suggest = options.suggest.unwrap_or(get_config().throw_suggestions);
+            let (pierce_shadow_dom, traverse_iframes) =
+                (options.pierce_shadow_dom, options.traverse_iframes);
             let element = $query(container, matcher, options)?;
-            let suggest = get_config().throw_suggestions;
 
             if let Some(element) = Option::<&HtmlElement>::from(&element) {
                 if suggest {
                     let suggestion = get_suggested_query(element, Some($variant), None);
                     if let Some(suggestion) = suggestion {
                         if !$query_by_all_name.ends_with(&suggestion.query_name.to_string()) {
-                            return Err(get_suggestion_error(
-                                suggestion.to_string(),
-                                container.clone().into(),
+                            return Err($crate::query_helpers::with_query_traversal(
+                                pierce_shadow_dom,
+                                traverse_iframes,
+                                || {
+                                    get_suggestion_error(
+                                        suggestion.to_string(),
+                                        container.clone().into(),
+                                    )
+                                },
                             ));
                         }
                     }
@@ -207,11 +349,49 @@ macro_rules! wrap_all_by_query_with_suggestion {
             matcher: M,
             options: $options_type,
         ) -> Result<Vec<HtmlElement>, QueryError> {
+            let matcher: $matcher_type = matcher.into();
+
+            let suggest = options.suggest.unwrap_or(get_config().throw_suggestions);
+            let (pierce_shadow_dom, traverse_iframes) =
+                (options.pierce_shadow_dom, options.traverse_iframes);
             let els = $query(container, matcher, options)?;
-            let suggest = get_config().throw_suggestions;
 
             if !els.is_empty() && suggest {
-                // TODO
+                let suggestions = els
+                    .iter()
+                    .map(|element| get_suggested_query(element, Some($variant), None))
+                    .collect::<Vec<_>>();
+                // Displaying a suggestion logs its warning, so compare the parts without side effects instead.
+                let keys = suggestions
+                    .iter()
+                    .map(|suggestion| {
+                        suggestion.as_ref().map(|suggestion| {
+                            (
+                                suggestion.query_method.clone(),
+                                suggestion.query_matcher.to_string(),
+                                suggestion.query_options.to_string(),
+                            )
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                // Only suggest if all elements have the same suggestion.
+                if keys.iter().all(|key| *key == keys[0]) {
+                    if let Some(suggestion) = &suggestions[0] {
+                        if !$query_by_all_name.ends_with(&suggestion.query_name.to_string()) {
+                            return Err($crate::query_helpers::with_query_traversal(
+                                pierce_shadow_dom,
+                                traverse_iframes,
+                                || {
+                                    get_suggestion_error(
+                                        suggestion.to_string(),
+                                        container.clone().into(),
+                                    )
+                                },
+                            ));
+                        }
+                    }
+                }
             }
 
             Ok(els)
@@ -219,22 +399,31 @@ macro_rules! wrap_all_by_query_with_suggestion {
     };
 }
 
+/// Builds the `get`, `query` and `find` variants of a custom query from its `query_all_by` function.
+///
+/// Takes the `query_all_by` function, functions returning the messages for the multiple and missing element errors,
+/// the name of the query, and the matcher and options types. The queries are generated in a module called `internal`,
+/// so this can only be called once per module. The options type needs a `suggest: Option<bool>` field, like
+/// [`MatcherOptions`](crate::MatcherOptions), which overrides the `throw_suggestions` option. Its
+/// `pierce_shadow_dom: Option<bool>` and `traverse_iframes: Option<bool>` fields decide whether the DOM printed in
+/// errors includes shadow roots and frames.
+///
+/// See [Custom Queries](https://testing-library.rustforweb.org/core/advanced/custom-queries.html) for an example.
 #[macro_export]
 macro_rules! build_queries {
     ($query_by_all:ident, $get_multiple_error:ident, $get_missing_error:ident, $name:ident, $matcher_type:ty, $options_type:ty) => {
-        paste::paste! {
+        $crate::paste::paste! {
             mod internal {
-                use web_sys::HtmlElement;
+                use $crate::web_sys::HtmlElement;
 
                 use $crate::{
-                    config::get_config,
-                    error::QueryError,
-                    query_helpers::{get_element_error, get_multiple_elements_found_error, get_suggestion_error},
-                    types::{Variant, WaitForOptions},
-                    suggestions::{get_suggested_query},
-                    wait_for::{wait_for},
+                    QueryError, Variant, WaitForOptions, get_config, get_element_error,
+                    get_multiple_elements_found_error, get_suggested_query, get_suggestion_error, wait_for,
                 };
 
+                // Matcher and options types may be given relative to the calling module.
+                #[allow(unused_imports)]
+                use super::*;
                 use super::{$query_by_all, $get_multiple_error, $get_missing_error};
 
                 // Query all by
@@ -9,9 +9,16 @@ use dom_accessibility_api::{
 };
 use ordered_hash_map::OrderedHashMap;
 use wasm_bindgen::JsCast;
-use web_sys::{Element, HtmlElement, HtmlInputElement, HtmlOptionElement};
-
-use crate::{pretty_dom, types::ByRoleOptionsCurrent, util::html_collection_to_vec};
+use web_sys::{Element, HtmlOptionElement};
+
+use crate::{
+    pretty_dom,
+    traversal::{
+        Traversal, as_html_element, as_input_element, get_child_elements, get_parent_element,
+    },
+    types::{ByRoleOptionsCurrent, CheckedState, HasPopup},
+    util::html_collection_to_vec,
+};
 
 struct ElementRole {
     r#match: Box<dyn Fn(&Element) -> bool + Send + Sync>,
@@ -85,8 +92,7 @@ static ELEMENT_ROLE_LIST: LazyLock<Vec<ElementRole>> = LazyLock::new(|| {
         result.push(ElementRole {
             r#match: Box::new(move |element| {
                 if type_text_index.is_some()
-                    && element
-                        .dyn_ref::<HtmlInputElement>()
+                    && as_input_element(element)
                         .is_none_or(|input_element| input_element.type_() != "text")
                 {
                     return false;
@@ -105,10 +111,7 @@ static ELEMENT_ROLE_LIST: LazyLock<Vec<ElementRole>> = LazyLock::new(|| {
 });
 
 pub fn is_subtree_inaccessible(element: &Element) -> bool {
-    if element
-        .dyn_ref::<HtmlElement>()
-        .is_some_and(|html_element| html_element.hidden())
-    {
+    if as_html_element(element).is_some_and(|html_element| html_element.hidden()) {
         return true;
     }
 
@@ -167,7 +170,7 @@ pub fn is_inaccessible(element: &Element) -> bool {
             return true;
         }
 
-        current_element = element.parent_element();
+        current_element = get_parent_element(element);
     }
 
     false
@@ -186,26 +189,29 @@ pub fn get_implicit_aria_roles(current_node: &Element) -> Vec<AriaRoleDefinition
 #[derive(Clone, Default)]
 pub struct GetRolesOptions {
     pub hidden: Option<bool>,
+    pub pierce_shadow_dom: Option<bool>,
+    pub traverse_iframes: Option<bool>,
 }
 
 pub fn get_roles(
     container: Element,
     options: GetRolesOptions,
 ) -> OrderedHashMap<AriaRoleDefinitionKey, Vec<Element>, RandomState> {
-    fn flatten_dom(element: Element) -> Vec<Element> {
+    fn flatten_dom(element: Element, traversal: Traversal) -> Vec<Element> {
         let mut elements = vec![element.clone()];
         elements.extend(
-            html_collection_to_vec::<Element>(element.children())
+            get_child_elements(&element, traversal)
                 .into_iter()
-                .flat_map(flatten_dom)
+                .flat_map(|child| flatten_dom(child, traversal))
                 .collect::<Vec<_>>(),
         );
         elements
     }
 
     let hidden = options.hidden.unwrap_or(false);
+    let traversal = Traversal::new(options.pierce_shadow_dom, options.traverse_iframes);
 
-    flatten_dom(container)
+    flatten_dom(container, traversal)
         .into_iter()
         .filter(|element| hidden || !is_inaccessible(element))
         .fold(
@@ -244,13 +250,17 @@ pub fn get_roles(
 pub struct PrettyRolesOptions {
     pub hidden: Option<bool>,
     pub include_description: Option<bool>,
+    pub pierce_shadow_dom: Option<bool>,
+    pub traverse_iframes: Option<bool>,
 }
 
-fn pretty_roles(dom: Element, options: PrettyRolesOptions) -> String {
+pub(crate) fn pretty_roles(dom: Element, options: PrettyRolesOptions) -> String {
     let roles = get_roles(
         dom,
         GetRolesOptions {
             hidden: options.hidden,
+            pierce_shadow_dom: options.pierce_shadow_dom,
+            traverse_iframes: options.traverse_iframes,
         },
     );
 
@@ -273,8 +283,7 @@ fn pretty_roles(dom: Element, options: PrettyRolesOptions) -> String {
                             element
                                 .clone_node_with_deep(false)
                                 .expect("Node should be cloned.")
-                                .dyn_into::<Element>()
-                                .expect("Cloned node should be an Element.")
+                                .unchecked_into::<Element>()
                                 .into(),
                         ),
                         None,
@@ -282,7 +291,7 @@ fn pretty_roles(dom: Element, options: PrettyRolesOptions) -> String {
 
                     if options.include_description.unwrap_or(false) {
                         let description_string = format!(
-                            "Description \"{}\":",
+                            "Description \"{}\":\n",
                             compute_accessible_description(
                                 element,
                                 ComputeTextAlternativeOptions::default()
@@ -324,25 +333,25 @@ pub fn compute_aria_busy(element: &Element) -> bool {
         .is_some_and(|value| value == "true")
 }
 
-pub fn compute_aria_checked(element: &Element) -> Option<bool> {
-    if let Some(input_element) = element.dyn_ref::<HtmlInputElement>() {
+pub fn compute_aria_checked(element: &Element) -> Option<CheckedState> {
+    if let Some(input_element) = as_input_element(element) {
         // Implicit value from HTML-AAM mappings:
         // https://www.w3.org/TR/html-aam-1.0/#att-indeterminate
         // https://www.w3.org/TR/html-aam-1.0/#att-checked
         if input_element.indeterminate() {
-            None
+            Some(CheckedState::Mixed)
         } else {
-            Some(input_element.checked())
+            Some(input_element.checked().into())
         }
     } else {
         // Explicit value.
-        check_boolean_attribute(element, "aria-checked")
+        check_tristate_attribute(element, "aria-checked")
     }
 }
 
-pub fn compute_aria_pressed(element: &Element) -> Option<bool> {
+pub fn compute_aria_pressed(element: &Element) -> Option<CheckedState> {
     // https://www.w3.org/TR/wai-aria-1.1/#aria-pressed
-    check_boolean_attribute(element, "aria-pressed")
+    check_tristate_attribute(element, "aria-pressed")
 }
 
 pub fn compute_aria_current(element: &Element) -> ByRoleOptionsCurrent {
@@ -363,6 +372,75 @@ pub fn compute_aria_expanded(element: &Element) -> Option<bool> {
     check_boolean_attribute(element, "aria-expanded")
 }
 
+pub fn compute_aria_disabled(element: &Element) -> bool {
+    // Implicit value from HTML-AAM mappings, including inheritance from disabled fieldsets and optgroups:
+    // https://www.w3.org/TR/html-aam-1.0/#att-disabled
+    if element.matches(":disabled").unwrap_or(false) {
+        return true;
+    }
+
+    // Explicit value, which applies to all descendants: https://www.w3.org/TR/wai-aria-1.2/#aria-disabled.
+    let mut current_element = Some(element.clone());
+    while let Some(element) = current_element {
+        if check_boolean_attribute(&element, "aria-disabled") == Some(true) {
+            return true;
+        }
+
+        current_element = get_parent_element(&element);
+    }
+
+    false
+}
+
+pub fn compute_aria_invalid(element: &Element) -> bool {
+    // https://www.w3.org/TR/wai-aria-1.2/#aria-invalid
+    element
+        .get_attribute("aria-invalid")
+        .is_some_and(|value| !value.is_empty() && value != "false")
+}
+
+pub fn compute_aria_required(element: &Element) -> bool {
+    // Implicit value from HTML-AAM mappings: https://www.w3.org/TR/html-aam-1.0/#att-required.
+    element.matches(":required").unwrap_or(false)
+        // Explicit value: https://www.w3.org/TR/wai-aria-1.2/#aria-required.
+        || check_boolean_attribute(element, "aria-required") == Some(true)
+}
+
+pub fn compute_aria_readonly(element: &Element) -> bool {
+    // Implicit value from HTML-AAM mappings: https://www.w3.org/TR/html-aam-1.0/#att-readonly.
+    (matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA") && element.has_attribute("readonly"))
+        // Explicit value: https://www.w3.org/TR/wai-aria-1.2/#aria-readonly.
+        || check_boolean_attribute(element, "aria-readonly") == Some(true)
+}
+
+pub fn compute_aria_multiselectable(element: &Element) -> bool {
+    // Implicit value from HTML-AAM mappings: https://www.w3.org/TR/html-aam-1.0/#att-multiple-select.
+    (element.tag_name() == "SELECT" && element.has_attribute("multiple"))
+        // Explicit value: https://www.w3.org/TR/wai-aria-1.2/#aria-multiselectable.
+        || check_boolean_attribute(element, "aria-multiselectable") == Some(true)
+}
+
+pub fn compute_aria_haspopup(element: &Element) -> HasPopup {
+    // Explicit value: https://www.w3.org/TR/wai-aria-1.2/#aria-haspopup.
+    match element.get_attribute("aria-haspopup").as_deref() {
+        // `true` is equivalent to `menu`.
+        Some("true" | "menu") => return HasPopup::Menu,
+        Some("listbox") => return HasPopup::Listbox,
+        Some("tree") => return HasPopup::Tree,
+        Some("grid") => return HasPopup::Grid,
+        Some("dialog") => return HasPopup::Dialog,
+        Some("false") => return HasPopup::False,
+        _ => {}
+    }
+
+    // Comboboxes have an implicit value of `listbox`: https://www.w3.org/TR/wai-aria-1.2/#combobox.
+    if has_role(element, AriaRoleDefinitionKey::Combobox) {
+        HasPopup::Listbox
+    } else {
+        HasPopup::False
+    }
+}
+
 fn check_boolean_attribute(element: &Element, attribute: &str) -> Option<bool> {
     let attribute_value = element.get_attribute(attribute);
 
@@ -379,23 +457,121 @@ fn check_boolean_attribute(element: &Element, attribute: &str) -> Option<bool> {
     }
 }
 
-pub fn compute_heading_level(element: &Element) -> Option<usize> {
+fn check_tristate_attribute(element: &Element, attribute: &str) -> Option<CheckedState> {
+    if element
+        .get_attribute(attribute)
+        .is_some_and(|value| value == "mixed")
+    {
+        Some(CheckedState::Mixed)
+    } else {
+        check_boolean_attribute(element, attribute).map(CheckedState::from)
+    }
+}
+
+pub fn compute_aria_level(element: &Element) -> Option<usize> {
     // Explicit value: https://www.w3.org/TR/wai-aria-1.2/#aria-level.
-    element
+    if let Some(level) = element
         .get_attribute("aria-level")
         .and_then(|level| level.parse::<usize>().ok())
-        .or_else(|| {
-            // Implicit value: https://w3c.github.io/html-aam/#el-h1-h6.
-            match element.tag_name().as_str() {
-                "H1" => Some(1),
-                "H2" => Some(2),
-                "H3" => Some(3),
-                "H4" => Some(4),
-                "H5" => Some(5),
-                "H6" => Some(6),
-                _ => None,
+    {
+        return Some(level);
+    }
+
+    // Implicit value: https://w3c.github.io/html-aam/#el-h1-h6.
+    match element.tag_name().as_str() {
+        "H1" => return Some(1),
+        "H2" => return Some(2),
+        "H3" => return Some(3),
+        "H4" => return Some(4),
+        "H5" => return Some(5),
+        "H6" => return Some(6),
+        _ => {}
+    }
+
+    if has_role(element, AriaRoleDefinitionKey::Treeitem) {
+        // Implicit value: the number of groups between the tree item and its tree, plus one.
+        // See https://www.w3.org/TR/wai-aria-1.2/#treeitem.
+        let mut level = 1;
+        let mut ancestor = element.parent_element();
+        while let Some(current) = ancestor {
+            if has_role(&current, AriaRoleDefinitionKey::Tree) {
+                break;
             }
-        })
+            if has_role(&current, AriaRoleDefinitionKey::Group) {
+                level += 1;
+            }
+            ancestor = current.parent_element();
+        }
+        return Some(level);
+    }
+
+    if has_role(element, AriaRoleDefinitionKey::Listitem) {
+        // Implicit value: the number of lists containing the list item.
+        // See https://www.w3.org/TR/wai-aria-1.2/#listitem.
+        let mut level = 0;
+        let mut ancestor = element.parent_element();
+        while let Some(current) = ancestor {
+            if has_role(&current, AriaRoleDefinitionKey::List) {
+                level += 1;
+            }
+            ancestor = current.parent_element();
+        }
+        return (level > 0).then_some(level);
+    }
+
+    None
+}
+
+pub fn compute_aria_posinset(element: &Element) -> Option<usize> {
+    // Explicit value: https://www.w3.org/TR/wai-aria-1.2/#aria-posinset.
+    if let Some(position) = element.get_attribute("aria-posinset") {
+        return position.parse::<usize>().ok();
+    }
+
+    // Implicit value: the position among the siblings with the same role.
+    get_set_siblings(element)?
+        .iter()
+        .position(|sibling| sibling == element)
+        .map(|index| index + 1)
+}
+
+pub fn compute_aria_setsize(element: &Element) -> Option<usize> {
+    // Explicit value: https://www.w3.org/TR/wai-aria-1.2/#aria-setsize.
+    // A value of `-1` indicates an unknown set size and does not match any size.
+    if let Some(size) = element.get_attribute("aria-setsize") {
+        return size.parse::<usize>().ok();
+    }
+
+    // Implicit value: the number of siblings with the same role.
+    get_set_siblings(element).map(|siblings| siblings.len())
+}
+
+/// Siblings of the element (including itself) which form a set, i.e. which have the same role.
+fn get_set_siblings(element: &Element) -> Option<Vec<Element>> {
+    let role = get_first_role(element)?;
+    let parent = element.parent_element()?;
+
+    Some(
+        html_collection_to_vec::<Element>(parent.children())
+            .into_iter()
+            .filter(|sibling| get_first_role(sibling).as_ref() == Some(&role))
+            .collect(),
+    )
+}
+
+/// First token of the explicit role or, if there is none, the first implicit role of the element.
+pub fn get_first_role(element: &Element) -> Option<String> {
+    match element.get_attribute("role") {
+        Some(explicit_role) => explicit_role.split(' ').next().map(str::to_owned),
+        None => get_implicit_aria_roles(element)
+            .first()
+            .map(|role| role.to_string()),
+    }
+}
+
+/// Whether the first role of the element, see [`get_first_role`], equals `role`.
+fn has_role(element: &Element, role: AriaRoleDefinitionKey) -> bool {
+    get_first_role(element).is_some_and(|first_role| first_role == role.to_string())
 }
 
 pub fn compute_aria_value_now(element: &Element) -> Option<f64> {
@@ -1,30 +1,75 @@
+use aria_query::AriaRole;
+use dom_accessibility_api::{ComputeTextAlternativeOptions, compute_accessible_name};
+use js_sys::Reflect;
+use regex::Regex;
+use wasm_bindgen::JsCast;
 use web_sys::HtmlElement;
 
 use crate::{
-    MatcherOptions,
-    types::{Method, Suggestion, Variant},
+    config::get_config,
+    get_node_text::get_node_text,
+    label_helpers::get_labels,
+    matches::get_default_normalizer,
+    role_helpers::{get_first_role, is_inaccessible},
+    types::{
+        ByRoleMatcher, ByRoleOptions, DefaultNormalizerOptions, Matcher, MatcherOptions, Method,
+        SelectorMatcherOptions, Suggestion, SuggestionMatcher, SuggestionOptions, Variant,
+    },
 };
 
+fn get_regex_matcher(content: &str) -> Matcher {
+    Regex::new(&format!("(?i){}", regex::escape(&content.to_lowercase())))
+        .expect("Regex should be valid.")
+        .into()
+}
+
 fn make_suggestion(
     query_name: Method,
-    _element: &HtmlElement,
+    element: &HtmlElement,
     content: String,
     variant: Variant,
-    _name: Option<String>,
+    name: Option<String>,
 ) -> Suggestion {
-    let warning = None;
-
-    let query_matcher = content.into();
-    let query_options = MatcherOptions::default();
+    let mut warning = None;
 
-    // if let Some(name) = name {
-    // query_options.name =
-    // }
+    let (query_matcher, query_options) = match query_name {
+        Method::Role => {
+            let mut options = ByRoleOptions::default();
+            if let Some(name) = name {
+                options = options.name(name);
+            }
+            if is_inaccessible(element) {
+                options = options.hidden(true);
+                warning = Some(
+                    "Element is inaccessible. This means that the element and all its children are invisible to \
+                    screen readers. If it is hidden with `aria-hidden`, `hidden` or CSS, make sure this is intended."
+                        .to_owned(),
+                );
+            }
 
-    // if query_name == Method::Role && is_inaccessible(element) {
-    // query_options.
-    // warning = Some();
-    // }
+            (
+                SuggestionMatcher::ByRole(
+                    content
+                        .parse::<AriaRole>()
+                        .map(ByRoleMatcher::Role)
+                        .unwrap_or(ByRoleMatcher::String(content)),
+                ),
+                SuggestionOptions::ByRole(options),
+            )
+        }
+        Method::TestId => (
+            SuggestionMatcher::Matcher(content.into()),
+            SuggestionOptions::Matcher(MatcherOptions::default()),
+        ),
+        Method::LabelText | Method::Text => (
+            SuggestionMatcher::Matcher(get_regex_matcher(&content)),
+            SuggestionOptions::SelectorMatcher(SelectorMatcherOptions::default()),
+        ),
+        _ => (
+            SuggestionMatcher::Matcher(get_regex_matcher(&content)),
+            SuggestionOptions::Matcher(MatcherOptions::default()),
+        ),
+    };
 
     let query_method = format!("{variant}_by_{query_name}");
 
@@ -38,15 +83,15 @@ fn make_suggestion(
     }
 }
 
-fn can_suggest<T>(
+fn can_suggest(
     current_method: Method,
     requested_method: Option<Method>,
-    data: Option<T>,
-) -> Option<T> {
+    data: Option<String>,
+) -> Option<String> {
     if requested_method.is_none()
         || requested_method.is_some_and(|requested_method| requested_method == current_method)
     {
-        data
+        data.filter(|data| !data.is_empty())
     } else {
         None
     }
@@ -58,8 +103,85 @@ pub fn get_suggested_query(
     method: Option<Method>,
 ) -> Option<Suggestion> {
     let variant = variant.unwrap_or(Variant::Get);
+    let config = get_config();
+
+    if element.matches(&config.default_ignore).unwrap_or(false) {
+        return None;
+    }
+
+    // Only the first token of an explicit role is used, see `query_fallbacks` in `by_role` queries.
+    let role = get_first_role(element);
+    if role.as_deref() != Some("generic")
+        && let Some(role) = can_suggest(Method::Role, method, role)
+    {
+        let name = compute_accessible_name(element, ComputeTextAlternativeOptions::default());
+
+        return Some(make_suggestion(
+            Method::Role,
+            element,
+            role,
+            variant,
+            Some(name).filter(|name| !name.is_empty()),
+        ));
+    }
+
+    let document_element = element
+        .owner_document()
+        .and_then(|document| document.document_element())
+        .expect("Owner document should have document element.")
+        .unchecked_into::<HtmlElement>();
+    let label_text = get_labels(&document_element, element, None)
+        .into_iter()
+        .filter_map(|label| label.content)
+        .collect::<Vec<_>>()
+        .join(" ");
+    if let Some(label_text) = can_suggest(Method::LabelText, method, Some(label_text)) {
+        return Some(make_suggestion(
+            Method::LabelText,
+            element,
+            label_text,
+            variant,
+            None,
+        ));
+    }
+
+    let placeholder_text = element.get_attribute("placeholder");
+    if let Some(placeholder_text) = can_suggest(Method::PlaceholderText, method, placeholder_text) {
+        return Some(make_suggestion(
+            Method::PlaceholderText,
+            element,
+            placeholder_text,
+            variant,
+            None,
+        ));
+    }
 
-    // TODO
+    let normalize = get_default_normalizer(DefaultNormalizerOptions::default());
+
+    let text_content = normalize(get_node_text(element));
+    if let Some(text_content) = can_suggest(Method::Text, method, Some(text_content)) {
+        return Some(make_suggestion(
+            Method::Text,
+            element,
+            text_content,
+            variant,
+            None,
+        ));
+    }
+
+    // Only form controls and a few other elements have a string value.
+    let value = Reflect::get(element, &"value".into())
+        .ok()
+        .and_then(|value| value.as_string());
+    if let Some(value) = can_suggest(Method::DisplayValue, method, value) {
+        return Some(make_suggestion(
+            Method::DisplayValue,
+            element,
+            normalize(value),
+            variant,
+            None,
+        ));
+    }
 
     let alt = element.get_attribute("alt");
     if let Some(alt) = can_suggest(Method::AltText, method, alt) {
@@ -72,5 +194,27 @@ pub fn get_suggested_query(
         ));
     }
 
+    let title = element.get_attribute("title");
+    if let Some(title) = can_suggest(Method::Title, method, title) {
+        return Some(make_suggestion(
+            Method::Title,
+            element,
+            title,
+            variant,
+            None,
+        ));
+    }
+
+    let test_id = element.get_attribute(&config.test_id_attribute);
+    if let Some(test_id) = can_suggest(Method::TestId, method, test_id) {
+        return Some(make_suggestion(
+            Method::TestId,
+            element,
+            test_id,
+            variant,
+            None,
+        ));
+    }
+
     None
 }
@@ -0,0 +1,273 @@
+use std::{cell::RefCell, collections::BTreeMap};
+
+use js_sys::{Date, Function, Reflect};
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+
+/// Source of time for async utilities.
+///
+/// When the clock is fake, `wait_for` advances it instead of waiting for real time to pass.
+pub trait Clock: Send + Sync {
+    /// Current time in milliseconds.
+    fn now(&self) -> f64;
+
+    /// Whether time is faked.
+    fn is_fake(&self) -> bool;
+
+    /// Advance fake time by `ms` milliseconds, running all timers which are due in that time.
+    fn advance_timers_by(&self, ms: i32);
+}
+
+/// Clock which uses the built-in fake timers when they are enabled and real time otherwise.
+pub struct DefaultClock;
+
+impl Clock for DefaultClock {
+    fn now(&self) -> f64 {
+        Date::now()
+    }
+
+    fn is_fake(&self) -> bool {
+        fake_timers_are_enabled()
+    }
+
+    fn advance_timers_by(&self, ms: i32) {
+        advance_timers_by(ms);
+    }
+}
+
+// Browsers run animation frames at roughly 60 frames per second.
+const ANIMATION_FRAME_DELAY: f64 = 16.0;
+
+const RUN_ALL_TIMERS_LIMIT: usize = 100_000;
+
+#[derive(Clone, Copy, PartialEq)]
+enum FakeTimerKind {
+    Timeout,
+    Interval,
+    AnimationFrame,
+}
+
+struct FakeTimer {
+    kind: FakeTimerKind,
+    callback: Function,
+    delay: f64,
+    at: f64,
+}
+
+struct FakeTimers {
+    now: f64,
+    next_id: i32,
+    timers: BTreeMap<i32, FakeTimer>,
+    /// Original values of the patched properties, restored by [`use_real_timers`].
+    originals: Vec<(JsValue, &'static str, JsValue)>,
+}
+
+impl FakeTimers {
+    fn schedule(&mut self, kind: FakeTimerKind, callback: JsValue, delay: f64) -> i32 {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        if let Ok(callback) = callback.dyn_into::<Function>() {
+            // Browsers clamp negative delays and repeating intervals need to make progress.
+            let delay = match kind {
+                FakeTimerKind::Interval => delay.max(1.0),
+                _ => delay.max(0.0),
+            };
+
+            self.timers.insert(
+                id,
+                FakeTimer {
+                    kind,
+                    callback,
+                    delay,
+                    at: self.now + delay,
+                },
+            );
+        }
+
+        id
+    }
+
+    fn clear(&mut self, id: JsValue) {
+        if let Some(id) = id.as_f64() {
+            self.timers.remove(&(id as i32));
+        }
+    }
+
+    /// Removes (or reschedules) the next timer which is due at or before `until` and moves the time to it.
+    fn next_due(&mut self, until: Option<f64>) -> Option<(FakeTimerKind, Function)> {
+        let (&id, timer) = self
+            .timers
+            .iter_mut()
+            .min_by(|(a_id, a), (b_id, b)| a.at.total_cmp(&b.at).then(a_id.cmp(b_id)))?;
+
+        if until.is_some_and(|until| timer.at > until) {
+            return None;
+        }
+
+        self.now = self.now.max(timer.at);
+
+        let kind = timer.kind;
+        let callback = timer.callback.clone();
+        if kind == FakeTimerKind::Interval {
+            timer.at += timer.delay;
+        } else {
+            self.timers.remove(&id);
+        }
+
+        Some((kind, callback))
+    }
+}
+
+thread_local! {
+    static FAKE_TIMERS: RefCell<Option<FakeTimers>> = const { RefCell::new(None) };
+}
+
+fn with_fake_timers<R>(f: impl FnOnce(&mut FakeTimers) -> R) -> Option<R> {
+    FAKE_TIMERS.with_borrow_mut(|fake_timers| fake_timers.as_mut().map(f))
+}
+
+fn patch(
+    target: &JsValue,
+    property: &'static str,
+    value: JsValue,
+) -> (JsValue, &'static str, JsValue) {
+    let key = JsValue::from_str(property);
+    let original = Reflect::get(target, &key).expect("Property should be read.");
+    Reflect::set(target, &key, &value).expect("Property should be set.");
+
+    (target.clone(), property, original)
+}
+
+fn run(kind: FakeTimerKind, callback: Function) {
+    let result = match kind {
+        FakeTimerKind::AnimationFrame => {
+            let now = with_fake_timers(|fake_timers| fake_timers.now).unwrap_or_default();
+            callback.call1(&JsValue::UNDEFINED, &JsValue::from_f64(now))
+        }
+        _ => callback.call0(&JsValue::UNDEFINED),
+    };
+
+    if let Err(error) = result {
+        log::error!("Fake timer callback failed: {error:?}");
+    }
+}
+
+fn warn_fake_timers_not_enabled() {
+    log::warn!(
+        "A function to advance timers was called but the timers APIs are not replaced with fake timers. \
+        Call `use_fake_timers()` first."
+    );
+}
+
+/// Whether the built-in fake timers are enabled.
+pub fn fake_timers_are_enabled() -> bool {
+    FAKE_TIMERS.with_borrow(|fake_timers| fake_timers.is_some())
+}
+
+/// Replaces `setTimeout`, `clearTimeout`, `setInterval`, `clearInterval`, `requestAnimationFrame`,
+/// `cancelAnimationFrame` and `Date.now` with fake implementations, which only advance when told to.
+///
+/// Fake time starts at the current real time. Call [`use_real_timers`] to restore the original implementations.
+pub fn use_fake_timers() {
+    if fake_timers_are_enabled() {
+        use_real_timers();
+    }
+
+    let global: JsValue = js_sys::global().into();
+    let date = Reflect::get(&global, &JsValue::from_str("Date")).expect("Date should exist.");
+
+    let schedule = |kind: FakeTimerKind| {
+        Closure::<dyn FnMut(JsValue, JsValue) -> i32>::new(move |callback, delay: JsValue| {
+            with_fake_timers(|fake_timers| {
+                fake_timers.schedule(kind, callback, delay.as_f64().unwrap_or_default())
+            })
+            .unwrap_or_default()
+        })
+        .into_js_value()
+    };
+    let clear = || {
+        Closure::<dyn FnMut(JsValue)>::new(|id| {
+            with_fake_timers(|fake_timers| fake_timers.clear(id));
+        })
+        .into_js_value()
+    };
+    let request_animation_frame = Closure::<dyn FnMut(JsValue) -> i32>::new(|callback| {
+        with_fake_timers(|fake_timers| {
+            fake_timers.schedule(
+                FakeTimerKind::AnimationFrame,
+                callback,
+                ANIMATION_FRAME_DELAY,
+            )
+        })
+        .unwrap_or_default()
+    })
+    .into_js_value();
+    let now = Closure::<dyn FnMut() -> f64>::new(|| {
+        with_fake_timers(|fake_timers| fake_timers.now).unwrap_or_default()
+    })
+    .into_js_value();
+
+    let fake_timers = FakeTimers {
+        now: Date::now(),
+        next_id: 1,
+        timers: BTreeMap::new(),
+        originals: vec![
+            patch(&global, "setTimeout", schedule(FakeTimerKind::Timeout)),
+            patch(&global, "clearTimeout", clear()),
+            patch(&global, "setInterval", schedule(FakeTimerKind::Interval)),
+            patch(&global, "clearInterval", clear()),
+            patch(&global, "requestAnimationFrame", request_animation_frame),
+            patch(&global, "cancelAnimationFrame", clear()),
+            patch(&date, "now", now),
+        ],
+    };
+
+    FAKE_TIMERS.with_borrow_mut(|current| *current = Some(fake_timers));
+}
+
+/// Restores the original timer implementations replaced by [`use_fake_timers`]. Pending fake timers are discarded.
+pub fn use_real_timers() {
+    if let Some(fake_timers) = FAKE_TIMERS.with_borrow_mut(Option::take) {
+        for (target, property, original) in fake_timers.originals.into_iter().rev() {
+            Reflect::set(&target, &JsValue::from_str(property), &original)
+                .expect("Property should be restored.");
+        }
+    }
+}
+
+/// Advances fake time by `ms` milliseconds, running all timers which are due in that time in order.
+pub fn advance_timers_by(ms: i32) {
+    let Some(until) = with_fake_timers(|fake_timers| fake_timers.now + f64::from(ms.max(0))) else {
+        warn_fake_timers_not_enabled();
+        return;
+    };
+
+    while let Some((kind, callback)) =
+        with_fake_timers(|fake_timers| fake_timers.next_due(Some(until))).flatten()
+    {
+        run(kind, callback);
+    }
+
+    with_fake_timers(|fake_timers| fake_timers.now = fake_timers.now.max(until));
+}
+
+/// Runs all pending fake timers, including timers scheduled by those timers, until none are left.
+///
+/// # Panics
+///
+/// Panics after running 100000 timers, assuming an infinite loop (e.g. an interval).
+pub fn run_all_timers() {
+    if !fake_timers_are_enabled() {
+        warn_fake_timers_not_enabled();
+        return;
+    }
+
+    for _ in 0..RUN_ALL_TIMERS_LIMIT {
+        match with_fake_timers(|fake_timers| fake_timers.next_due(None)).flatten() {
+            Some((kind, callback)) => run(kind, callback),
+            None => return,
+        }
+    }
+
+    panic!("Aborting after running {RUN_ALL_TIMERS_LIMIT} timers, assuming an infinite loop!");
+}
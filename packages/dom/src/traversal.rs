@@ -0,0 +1,221 @@
+use std::cell::Cell;
+
+use js_sys::Reflect;
+use wasm_bindgen::JsCast;
+use web_sys::{
+    AssignedNodesOptions, Document, Element, HtmlElement, HtmlIFrameElement, HtmlInputElement,
+    HtmlSlotElement, Node, ShadowRoot,
+};
+
+use crate::{config::get_config, error::QueryError, util::node_list_to_vec};
+
+// Nodes in same-origin frames belong to another realm, so `instanceof` based casts like `dyn_ref` fail for them.
+// Node types and tag names are checked instead.
+
+/// Boundaries which queries descend into, in addition to the container's descendants.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Traversal {
+    /// Descend into open shadow roots.
+    pub pierce_shadow_dom: bool,
+    /// Descend into the documents of same-origin frames.
+    pub traverse_iframes: bool,
+}
+
+thread_local! {
+    static CURRENT_TRAVERSAL: Cell<Option<Traversal>> = const { Cell::new(None) };
+}
+
+impl Traversal {
+    /// Resolves the query options, falling back to the config.
+    pub fn new(pierce_shadow_dom: Option<bool>, traverse_iframes: Option<bool>) -> Self {
+        let config = get_config();
+
+        Self {
+            pierce_shadow_dom: pierce_shadow_dom.unwrap_or(config.pierce_shadow_dom),
+            traverse_iframes: traverse_iframes.unwrap_or(config.traverse_iframes),
+        }
+    }
+
+    /// Traversal set by [`Traversal::scope`], falling back to the config.
+    pub fn current() -> Self {
+        CURRENT_TRAVERSAL
+            .get()
+            .unwrap_or_else(|| Self::new(None, None))
+    }
+
+    /// Runs `f` with this traversal as the current one, e.g. so errors of a query print the boundaries it descends
+    /// into.
+    pub fn scope<T>(self, f: impl FnOnce() -> T) -> T {
+        let previous = CURRENT_TRAVERSAL.replace(Some(self));
+        let result = f();
+        CURRENT_TRAVERSAL.set(previous);
+        result
+    }
+}
+
+/// The node as an element, if it is one.
+pub fn as_element(node: &Node) -> Option<&Element> {
+    (node.node_type() == Node::ELEMENT_NODE).then(|| node.unchecked_ref::<Element>())
+}
+
+/// The element as an HTML element, if it is one, e.g. not an SVG element.
+pub fn as_html_element(element: &Element) -> Option<&HtmlElement> {
+    (element.namespace_uri().as_deref() == Some("http://www.w3.org/1999/xhtml"))
+        .then(|| element.unchecked_ref::<HtmlElement>())
+}
+
+/// The element as an `input` element, if it is one.
+pub fn as_input_element(element: &Element) -> Option<&HtmlInputElement> {
+    (element.tag_name() == "INPUT").then(|| element.unchecked_ref::<HtmlInputElement>())
+}
+
+/// Open shadow root of the element, if any.
+pub fn get_shadow_root(node: &Node) -> Option<ShadowRoot> {
+    as_element(node).and_then(Element::shadow_root)
+}
+
+/// Whether the node is a shadow root. Plain document fragments have no host.
+pub fn is_shadow_root(node: &Node) -> bool {
+    node.node_type() == Node::DOCUMENT_FRAGMENT_NODE
+        && Reflect::get(node, &"host".into()).is_ok_and(|host| host.is_object())
+}
+
+/// Same-origin document of the frame element, if any.
+pub fn get_frame_document(node: &Node) -> Option<Document> {
+    as_element(node)
+        .filter(|element| element.tag_name() == "IFRAME")
+        .and_then(|element| {
+            element
+                .unchecked_ref::<HtmlIFrameElement>()
+                .content_document()
+        })
+}
+
+/// Body of the same-origin document of the frame element, if any.
+pub fn get_frame_body(node: &Node) -> Option<HtmlElement> {
+    get_frame_document(node).and_then(|document| document.body())
+}
+
+/// Query all descendants of the container matching the selector, descending into the boundaries enabled by
+/// `traversal`.
+pub fn query_selector_all<T: JsCast>(
+    container: &Element,
+    selector: &str,
+    traversal: Traversal,
+) -> Result<Vec<T>, QueryError> {
+    if !traversal.pierce_shadow_dom && !traversal.traverse_iframes {
+        return Ok(node_list_to_vec::<T>(
+            container
+                .query_selector_all(selector)
+                .map_err(QueryError::JsError)?,
+        ));
+    }
+
+    let mut result = vec![];
+    collect_matching_descendants(container, selector, traversal, &mut result)?;
+    Ok(result)
+}
+
+fn collect_matching_descendants<T: JsCast>(
+    node: &Node,
+    selector: &str,
+    traversal: Traversal,
+    result: &mut Vec<T>,
+) -> Result<(), QueryError> {
+    // The shadow tree is rendered in place of the host's children, so it comes first.
+    if traversal.pierce_shadow_dom
+        && let Some(shadow_root) = get_shadow_root(node)
+    {
+        collect_matching_descendants(&shadow_root, selector, traversal, result)?;
+    }
+
+    let mut children = node_list_to_vec::<Node>(node.child_nodes());
+    if traversal.traverse_iframes
+        && let Some(body) = get_frame_body(node)
+    {
+        children.push(body.unchecked_into());
+    }
+
+    for child in children {
+        if let Some(element) = as_element(&child) {
+            if element.matches(selector).map_err(QueryError::JsError)? {
+                result.push(element.clone().unchecked_into::<T>());
+            }
+
+            collect_matching_descendants(element, selector, traversal, result)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Child elements of the element, preceded by the children of its open shadow root and followed by the body of its
+/// frame's document, as enabled by `traversal`.
+pub fn get_child_elements(element: &Element, traversal: Traversal) -> Vec<Element> {
+    let mut children = vec![];
+    if traversal.pierce_shadow_dom
+        && let Some(shadow_root) = element.shadow_root()
+    {
+        children.extend(node_list_to_vec::<Node>(shadow_root.child_nodes()));
+    }
+
+    children.extend(node_list_to_vec::<Node>(element.child_nodes()));
+
+    if traversal.traverse_iframes
+        && let Some(body) = get_frame_body(element)
+    {
+        children.push(body.unchecked_into());
+    }
+
+    children.iter().filter_map(as_element).cloned().collect()
+}
+
+/// Parent element of the element, continuing with the host element at the top of a shadow tree and with the frame
+/// element at the top of a frame's document.
+pub fn get_parent_element(element: &Element) -> Option<Element> {
+    if let Some(parent) = element.parent_element() {
+        return Some(parent);
+    }
+
+    let parent = element.parent_node()?;
+    match parent.node_type() {
+        Node::DOCUMENT_FRAGMENT_NODE if is_shadow_root(&parent) => {
+            Some(parent.unchecked_into::<ShadowRoot>().host())
+        }
+        Node::DOCUMENT_NODE => element
+            .owner_document()
+            .and_then(|document| document.default_view())
+            .and_then(|window| window.frame_element().ok().flatten()),
+        _ => None,
+    }
+}
+
+/// Children of the node in the flat tree, i.e. the open shadow root's children for a host and the assigned nodes (or
+/// fallback content) for a slot.
+pub fn get_flat_tree_child_nodes(node: &Node) -> Vec<Node> {
+    if let Some(shadow_root) = get_shadow_root(node) {
+        return node_list_to_vec::<Node>(shadow_root.child_nodes());
+    }
+
+    if is_slot(node) {
+        let options = AssignedNodesOptions::new();
+        options.set_flatten(true);
+
+        let assigned_nodes = node
+            .unchecked_ref::<HtmlSlotElement>()
+            .assigned_nodes_with_options(&options);
+        if assigned_nodes.length() > 0 {
+            return assigned_nodes
+                .iter()
+                .map(|node| node.unchecked_into::<Node>())
+                .collect();
+        }
+    }
+
+    node_list_to_vec::<Node>(node.child_nodes())
+}
+
+/// Whether the node is a `slot` element.
+pub fn is_slot(node: &Node) -> bool {
+    as_element(node).is_some_and(|element| element.tag_name() == "SLOT")
+}
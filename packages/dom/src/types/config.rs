@@ -1,18 +1,35 @@
-use std::sync::Arc;
+use std::{future::Future, pin::Pin, sync::Arc};
 
 use web_sys::Element;
 
-use crate::error::{FireEventError, QueryError};
+use crate::{
+    error::{FireEventError, QueryError},
+    timers::Clock,
+};
 
 pub type GetElementErrorFn = dyn Fn(Option<String>, Element) -> QueryError + Send + Sync;
 
 pub type EventWrapperFn =
     dyn Fn(&dyn Fn() -> Result<bool, FireEventError>) -> Result<bool, FireEventError> + Send + Sync;
 
+pub type AsyncWrapperFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+pub type AsyncWrapperFn = dyn Fn(AsyncWrapperFuture) -> AsyncWrapperFuture + Send + Sync;
+
 #[derive(Clone)]
 pub struct Config {
     pub test_id_attribute: String,
     pub event_wrapper: Arc<EventWrapperFn>,
+    /// Wraps every `wait_for` and `find_by` invocation, e.g. to flush a framework's scheduler.
+    /// The wrapper has to await the future it is given.
+    pub async_wrapper: Arc<AsyncWrapperFn>,
+    /// Wraps advancing fake timers in async utilities, e.g. to flush a framework's scheduler.
+    /// The wrapper has to await the future it is given.
+    pub advance_timers_wrapper: Arc<AsyncWrapperFn>,
+    /// Clock used by async utilities. Defaults to the built-in fake timers when enabled and real time otherwise.
+    pub clock: Arc<dyn Clock>,
+    /// Default value for the `timeout` option in `wait_for` and `find_by` queries.
+    pub async_util_timeout: i32,
     // TODO
     /// Default value for the `hidden` option in `by_role` queries.
     pub default_hidden: bool,
@@ -22,6 +39,10 @@ pub struct Config {
     pub show_original_stack_trace: bool,
     /// Throw errors with suggestions for better queries. Opt in so off by default.
     pub throw_suggestions: bool,
+    /// Traverse open shadow roots in queries. Opt in so off by default.
+    pub pierce_shadow_dom: bool,
+    /// Traverse the documents of same-origin frames in queries. Opt in so off by default.
+    pub traverse_iframes: bool,
     // Called when `get_by` queries fail.
     pub get_element_error: Arc<GetElementErrorFn>,
 }
@@ -34,6 +55,18 @@ impl Config {
         if let Some(event_wrapper) = other.event_wrapper {
             self.event_wrapper = event_wrapper;
         }
+        if let Some(async_wrapper) = other.async_wrapper {
+            self.async_wrapper = async_wrapper;
+        }
+        if let Some(advance_timers_wrapper) = other.advance_timers_wrapper {
+            self.advance_timers_wrapper = advance_timers_wrapper;
+        }
+        if let Some(clock) = other.clock {
+            self.clock = clock;
+        }
+        if let Some(async_util_timeout) = other.async_util_timeout {
+            self.async_util_timeout = async_util_timeout;
+        }
         if let Some(default_hidden) = other.default_hidden {
             self.default_hidden = default_hidden;
         }
@@ -46,6 +79,12 @@ impl Config {
         if let Some(throw_suggestions) = other.throw_suggestions {
             self.throw_suggestions = throw_suggestions;
         }
+        if let Some(pierce_shadow_dom) = other.pierce_shadow_dom {
+            self.pierce_shadow_dom = pierce_shadow_dom;
+        }
+        if let Some(traverse_iframes) = other.traverse_iframes {
+            self.traverse_iframes = traverse_iframes;
+        }
         if let Some(get_element_error) = other.get_element_error {
             self.get_element_error = get_element_error;
         }
@@ -56,6 +95,16 @@ impl Config {
 pub struct PartialConfig {
     pub test_id_attribute: Option<String>,
     pub event_wrapper: Option<Arc<EventWrapperFn>>,
+    /// Wraps every `wait_for` and `find_by` invocation, e.g. to flush a framework's scheduler.
+    /// The wrapper has to await the future it is given.
+    pub async_wrapper: Option<Arc<AsyncWrapperFn>>,
+    /// Wraps advancing fake timers in async utilities, e.g. to flush a framework's scheduler.
+    /// The wrapper has to await the future it is given.
+    pub advance_timers_wrapper: Option<Arc<AsyncWrapperFn>>,
+    /// Clock used by async utilities. Defaults to the built-in fake timers when enabled and real time otherwise.
+    pub clock: Option<Arc<dyn Clock>>,
+    /// Default value for the `timeout` option in `wait_for` and `find_by` queries.
+    pub async_util_timeout: Option<i32>,
     // TODO
     /// Default value for the `hidden` option in `by_role` queries.
     pub default_hidden: Option<bool>,
@@ -65,6 +114,10 @@ pub struct PartialConfig {
     pub show_original_stack_trace: Option<bool>,
     /// Throw errors with suggestions for better queries. Opt in so off by default.
     pub throw_suggestions: Option<bool>,
+    /// Traverse open shadow roots in queries. Opt in so off by default.
+    pub pierce_shadow_dom: Option<bool>,
+    /// Traverse the documents of same-origin frames in queries. Opt in so off by default.
+    pub traverse_iframes: Option<bool>,
     // Called when `get_by` queries fail.
     pub get_element_error: Option<Arc<GetElementErrorFn>>,
 }
@@ -80,6 +133,26 @@ impl PartialConfig {
         self
     }
 
+    pub fn async_wrapper(mut self, value: Arc<AsyncWrapperFn>) -> Self {
+        self.async_wrapper = Some(value);
+        self
+    }
+
+    pub fn advance_timers_wrapper(mut self, value: Arc<AsyncWrapperFn>) -> Self {
+        self.advance_timers_wrapper = Some(value);
+        self
+    }
+
+    pub fn clock(mut self, value: Arc<dyn Clock>) -> Self {
+        self.clock = Some(value);
+        self
+    }
+
+    pub fn async_util_timeout(mut self, value: i32) -> Self {
+        self.async_util_timeout = Some(value);
+        self
+    }
+
     pub fn default_hidden(mut self, value: bool) -> Self {
         self.default_hidden = Some(value);
         self
@@ -100,6 +173,16 @@ impl PartialConfig {
         self
     }
 
+    pub fn pierce_shadow_dom(mut self, value: bool) -> Self {
+        self.pierce_shadow_dom = Some(value);
+        self
+    }
+
+    pub fn traverse_iframes(mut self, value: bool) -> Self {
+        self.traverse_iframes = Some(value);
+        self
+    }
+
     pub fn get_element_error(mut self, value: Arc<GetElementErrorFn>) -> Self {
         self.get_element_error = Some(value);
         self
@@ -111,10 +194,16 @@ impl From<&Config> for PartialConfig {
         Self {
             test_id_attribute: Some(value.test_id_attribute.clone()),
             event_wrapper: Some(value.event_wrapper.clone()),
+            async_wrapper: Some(value.async_wrapper.clone()),
+            advance_timers_wrapper: Some(value.advance_timers_wrapper.clone()),
+            clock: Some(value.clock.clone()),
+            async_util_timeout: Some(value.async_util_timeout),
             default_hidden: Some(value.default_hidden),
             default_ignore: Some(value.default_ignore.clone()),
             show_original_stack_trace: Some(value.show_original_stack_trace),
             throw_suggestions: Some(value.throw_suggestions),
+            pierce_shadow_dom: Some(value.pierce_shadow_dom),
+            traverse_iframes: Some(value.traverse_iframes),
             get_element_error: Some(value.get_element_error.clone()),
         }
     }
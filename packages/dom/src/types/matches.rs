@@ -1,9 +1,11 @@
 use std::{fmt::Display, rc::Rc};
 
-use aria_query::AriaRole;
+use aria_query::{AriaAbstractRole, AriaRole};
 use regex::Regex;
 use web_sys::Element;
 
+use crate::error::QueryError;
+
 pub type MatcherFunction = dyn Fn(String, Option<&Element>) -> bool;
 
 #[derive(Clone)]
@@ -59,7 +61,106 @@ impl From<String> for Matcher {
     }
 }
 
-pub type ByRoleMatcher = AriaRole;
+pub type ByRoleMatcherFunction = dyn Fn(AriaRole, &Element) -> bool;
+
+/// Role or roles to query by.
+#[derive(Clone)]
+pub enum ByRoleMatcher {
+    /// A single role.
+    Role(AriaRole),
+    /// Any of the roles.
+    Roles(Vec<AriaRole>),
+    /// A role parsed at runtime, e.g. `"button"`. Unknown and abstract roles result in an error when querying.
+    String(String),
+    /// Called with each role of an element, matches if it returns `true` for any of them.
+    Function(Rc<ByRoleMatcherFunction>),
+}
+
+impl ByRoleMatcher {
+    /// Roles which can be matched, or `None` if any role can be matched.
+    pub(crate) fn roles(&self) -> Result<Option<Vec<AriaRole>>, QueryError> {
+        match self {
+            Self::Role(role) => Ok(Some(vec![*role])),
+            Self::Roles(roles) if roles.is_empty() => Err(QueryError::Unsupported(
+                "At least one role is required to query by roles.".to_owned(),
+            )),
+            Self::Roles(roles) => Ok(Some(roles.clone())),
+            Self::String(role) => match role.parse::<AriaRole>() {
+                Ok(role) => Ok(Some(vec![role])),
+                Err(_) if role.parse::<AriaAbstractRole>().is_ok() => {
+                    Err(QueryError::Unsupported(format!(
+                        "\"{role}\" is an abstract role and cannot be queried. Query one of its concrete subclass roles instead."
+                    )))
+                }
+                Err(_) => Err(QueryError::Unsupported(format!(
+                    "\"{role}\" is not a valid ARIA role."
+                ))),
+            },
+            Self::Function(_) => Ok(None),
+        }
+    }
+}
+
+impl Display for ByRoleMatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Role(role) => role.to_string(),
+                Self::Roles(roles) => roles
+                    .iter()
+                    .map(|role| role.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                Self::String(role) => role.clone(),
+                Self::Function(_) => "ByRoleMatcherFn".to_owned(),
+            }
+        )
+    }
+}
+
+impl From<AriaRole> for ByRoleMatcher {
+    fn from(value: AriaRole) -> Self {
+        Self::Role(value)
+    }
+}
+
+impl From<Vec<AriaRole>> for ByRoleMatcher {
+    fn from(value: Vec<AriaRole>) -> Self {
+        Self::Roles(value)
+    }
+}
+
+impl From<&[AriaRole]> for ByRoleMatcher {
+    fn from(value: &[AriaRole]) -> Self {
+        Self::Roles(value.to_vec())
+    }
+}
+
+impl<const N: usize> From<[AriaRole; N]> for ByRoleMatcher {
+    fn from(value: [AriaRole; N]) -> Self {
+        Self::Roles(value.to_vec())
+    }
+}
+
+impl From<&str> for ByRoleMatcher {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<String> for ByRoleMatcher {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<Rc<ByRoleMatcherFunction>> for ByRoleMatcher {
+    fn from(value: Rc<ByRoleMatcherFunction>) -> Self {
+        Self::Function(value)
+    }
+}
 
 pub type NormalizerFn = dyn Fn(String) -> String;
 
@@ -77,6 +178,8 @@ pub struct MatcherOptions {
     pub collapse_whitespace: Option<bool>,
     pub normalizer: Option<Rc<NormalizerFn>>,
     pub suggest: Option<bool>,
+    pub pierce_shadow_dom: Option<bool>,
+    pub traverse_iframes: Option<bool>,
 }
 
 impl MatcherOptions {
@@ -104,6 +207,16 @@ impl MatcherOptions {
         self.suggest = Some(value);
         self
     }
+
+    pub fn pierce_shadow_dom(mut self, value: bool) -> Self {
+        self.pierce_shadow_dom = Some(value);
+        self
+    }
+
+    pub fn traverse_iframes(mut self, value: bool) -> Self {
+        self.traverse_iframes = Some(value);
+        self
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -131,6 +244,8 @@ pub struct SelectorMatcherOptions {
     pub collapse_whitespace: Option<bool>,
     pub normalizer: Option<Rc<NormalizerFn>>,
     pub suggest: Option<bool>,
+    pub pierce_shadow_dom: Option<bool>,
+    pub traverse_iframes: Option<bool>,
     pub selector: Option<String>,
     pub ignore: Option<Ignore>,
 }
@@ -161,6 +276,16 @@ impl SelectorMatcherOptions {
         self
     }
 
+    pub fn pierce_shadow_dom(mut self, value: bool) -> Self {
+        self.pierce_shadow_dom = Some(value);
+        self
+    }
+
+    pub fn traverse_iframes(mut self, value: bool) -> Self {
+        self.traverse_iframes = Some(value);
+        self
+    }
+
     pub fn selector(mut self, value: &str) -> Self {
         self.selector = Some(value.to_owned());
         self
@@ -54,19 +54,66 @@ impl From<String> for ByRoleOptionsCurrent {
     }
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CheckedState {
+    True,
+    False,
+    Mixed,
+}
+
+impl From<bool> for CheckedState {
+    fn from(value: bool) -> Self {
+        match value {
+            true => Self::True,
+            false => Self::False,
+        }
+    }
+}
+
+/// Value of `aria-haspopup`. `True` matches any kind of popup.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HasPopup {
+    False,
+    True,
+    Menu,
+    Listbox,
+    Tree,
+    Grid,
+    Dialog,
+}
+
+impl From<bool> for HasPopup {
+    fn from(value: bool) -> Self {
+        match value {
+            true => Self::True,
+            false => Self::False,
+        }
+    }
+}
+
 #[derive(Clone, Default)]
 pub struct ByRoleOptions {
     pub suggest: Option<bool>,
     pub hidden: Option<bool>,
     pub selected: Option<bool>,
     pub busy: Option<bool>,
-    pub checked: Option<bool>,
-    pub pressed: Option<bool>,
+    pub checked: Option<CheckedState>,
+    pub pressed: Option<CheckedState>,
     pub current: Option<ByRoleOptionsCurrent>,
     pub expanded: Option<bool>,
     pub level: Option<usize>,
     pub value: Option<ByRoleOptionsValue>,
+    pub disabled: Option<bool>,
+    pub invalid: Option<bool>,
+    pub required: Option<bool>,
+    pub readonly: Option<bool>,
+    pub multiselectable: Option<bool>,
+    pub haspopup: Option<HasPopup>,
+    pub position_in_set: Option<usize>,
+    pub set_size: Option<usize>,
     pub query_fallbacks: Option<bool>,
+    pub pierce_shadow_dom: Option<bool>,
+    pub traverse_iframes: Option<bool>,
     pub name: Option<Matcher>,
     pub description: Option<Matcher>,
 }
@@ -92,13 +139,13 @@ impl ByRoleOptions {
         self
     }
 
-    pub fn checked(mut self, value: bool) -> Self {
-        self.checked = Some(value);
+    pub fn checked<C: Into<CheckedState>>(mut self, value: C) -> Self {
+        self.checked = Some(value.into());
         self
     }
 
-    pub fn pressed(mut self, value: bool) -> Self {
-        self.pressed = Some(value);
+    pub fn pressed<C: Into<CheckedState>>(mut self, value: C) -> Self {
+        self.pressed = Some(value.into());
         self
     }
 
@@ -122,11 +169,61 @@ impl ByRoleOptions {
         self
     }
 
+    pub fn disabled(mut self, value: bool) -> Self {
+        self.disabled = Some(value);
+        self
+    }
+
+    pub fn invalid(mut self, value: bool) -> Self {
+        self.invalid = Some(value);
+        self
+    }
+
+    pub fn required(mut self, value: bool) -> Self {
+        self.required = Some(value);
+        self
+    }
+
+    pub fn readonly(mut self, value: bool) -> Self {
+        self.readonly = Some(value);
+        self
+    }
+
+    pub fn multiselectable(mut self, value: bool) -> Self {
+        self.multiselectable = Some(value);
+        self
+    }
+
+    pub fn haspopup<H: Into<HasPopup>>(mut self, value: H) -> Self {
+        self.haspopup = Some(value.into());
+        self
+    }
+
+    pub fn position_in_set(mut self, value: usize) -> Self {
+        self.position_in_set = Some(value);
+        self
+    }
+
+    pub fn set_size(mut self, value: usize) -> Self {
+        self.set_size = Some(value);
+        self
+    }
+
     pub fn query_fallbacks(mut self, value: bool) -> Self {
         self.query_fallbacks = Some(value);
         self
     }
 
+    pub fn pierce_shadow_dom(mut self, value: bool) -> Self {
+        self.pierce_shadow_dom = Some(value);
+        self
+    }
+
+    pub fn traverse_iframes(mut self, value: bool) -> Self {
+        self.traverse_iframes = Some(value);
+        self
+    }
+
     pub fn name<M: Into<Matcher>>(mut self, value: M) -> Self {
         self.name = Some(value.into());
         self
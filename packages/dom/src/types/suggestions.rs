@@ -1,12 +1,12 @@
 use std::fmt::Display;
 
-use crate::types::{Matcher, MatcherOptions};
+use crate::types::{ByRoleMatcher, ByRoleOptions, Matcher, MatcherOptions, SelectorMatcherOptions};
 
 pub struct Suggestion {
     pub query_name: Method,
     pub query_method: String,
-    pub query_matcher: Matcher,
-    pub query_options: MatcherOptions,
+    pub query_matcher: SuggestionMatcher,
+    pub query_options: SuggestionOptions,
     pub variant: Variant,
     pub warning: Option<String>,
 }
@@ -17,14 +17,81 @@ impl Display for Suggestion {
             log::warn!("{warning}");
         }
 
-        let text = match &self.query_matcher {
-            Matcher::String(matcher) => format!("'{matcher}'"),
-            matcher => format!("{matcher}"),
-        };
+        write!(
+            f,
+            "{}({}, {})",
+            self.query_method, self.query_matcher, self.query_options
+        )
+    }
+}
 
-        let options = ", TODO";
+/// Matcher of a suggested query, displayed as Rust code.
+#[derive(Clone)]
+pub enum SuggestionMatcher {
+    ByRole(ByRoleMatcher),
+    Matcher(Matcher),
+}
+
+impl Display for SuggestionMatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ByRole(ByRoleMatcher::Role(role)) => write!(f, "AriaRole::{role:?}"),
+            Self::ByRole(ByRoleMatcher::Roles(roles)) => write!(
+                f,
+                "[{}]",
+                roles
+                    .iter()
+                    .map(|role| format!("AriaRole::{role:?}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::ByRole(ByRoleMatcher::String(role)) => write!(f, "{role:?}"),
+            Self::ByRole(ByRoleMatcher::Function(_)) => write!(f, "ByRoleMatcherFn"),
+            Self::Matcher(matcher) => write!(f, "{}", format_matcher(matcher)),
+        }
+    }
+}
+
+/// Options of a suggested query, displayed as Rust code.
+///
+/// Only the options which are set by suggestions are displayed, i.e. `name` and `hidden` for `by_role` queries.
+#[derive(Clone)]
+pub enum SuggestionOptions {
+    ByRole(ByRoleOptions),
+    Matcher(MatcherOptions),
+    SelectorMatcher(SelectorMatcherOptions),
+}
+
+impl Display for SuggestionOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ByRole(options) => {
+                write!(f, "ByRoleOptions::default()")?;
+
+                if let Some(name) = options.name.as_ref() {
+                    write!(f, ".name({})", format_matcher(name))?;
+                }
+                if let Some(hidden) = options.hidden {
+                    write!(f, ".hidden({hidden})")?;
+                }
+
+                Ok(())
+            }
+            Self::Matcher(_) => write!(f, "MatcherOptions::default()"),
+            Self::SelectorMatcher(_) => write!(f, "SelectorMatcherOptions::default()"),
+        }
+    }
+}
 
-        write!(f, "{}({}{})", self.query_method, text, options)
+fn format_matcher(matcher: &Matcher) -> String {
+    match matcher {
+        Matcher::Function(_) => "MatcherFn".to_owned(),
+        Matcher::Regex(regex) => format!(
+            "Regex::new({:?}).expect(\"Regex should be valid.\")",
+            regex.as_str()
+        ),
+        Matcher::Number(number) => format!("{number:?}"),
+        Matcher::String(string) => format!("{string:?}"),
     }
 }
 
@@ -1,12 +1,29 @@
-use web_sys::HtmlElement;
+use std::rc::Rc;
+
+use web_sys::{AbortSignal, HtmlElement, MutationObserverInit};
+
+use crate::error::QueryError;
+
+pub type OnTimeoutFn = dyn Fn(QueryError) -> QueryError;
 
 #[derive(Clone, Default)]
 pub struct WaitForOptions {
-    container: Option<HtmlElement>,
-    timeout: Option<i32>,
-    interval: Option<i32>,
-    // on_timeout
-    // mutation_observer_init
+    /// Element observed for mutations and printed in timeout errors. Defaults to the document element.
+    pub container: Option<HtmlElement>,
+    /// Maximum time in milliseconds to keep retrying the callback. Defaults to `async_util_timeout` from the config.
+    pub timeout: Option<i32>,
+    /// Time in milliseconds between attempts. Defaults to 50ms.
+    pub interval: Option<i32>,
+    /// Called with the last error when the timeout is reached. Defaults to adding the container's DOM to element
+    /// errors, other errors are returned unchanged. `find` queries return the error of their `get` query unchanged,
+    /// since it already includes the DOM.
+    pub on_timeout: Option<Rc<OnTimeoutFn>>,
+    /// Options for the mutation observer which re-runs the callback whenever the container changes.
+    /// Defaults to observing the subtree, child list, attributes and character data.
+    pub mutation_observer_init: Option<MutationObserverInit>,
+    /// Signal which cancels waiting when aborted, tearing down the timers and mutation observer.
+    /// Waiting then fails with [`QueryError::Aborted`] and the abort reason.
+    pub signal: Option<AbortSignal>,
 }
 
 impl WaitForOptions {
@@ -24,4 +41,44 @@ impl WaitForOptions {
         self.interval = Some(value);
         self
     }
+
+    pub fn on_timeout(mut self, value: Rc<OnTimeoutFn>) -> Self {
+        self.on_timeout = Some(value);
+        self
+    }
+
+    pub fn mutation_observer_init(mut self, value: MutationObserverInit) -> Self {
+        self.mutation_observer_init = Some(value);
+        self
+    }
+
+    pub fn signal(mut self, value: AbortSignal) -> Self {
+        self.signal = Some(value);
+        self
+    }
+}
+
+pub type WaitForElementToBeRemovedFn = dyn Fn() -> Result<Vec<HtmlElement>, QueryError>;
+
+pub enum ElementsOrCallback {
+    Elements(Vec<HtmlElement>),
+    Callback(Box<WaitForElementToBeRemovedFn>),
+}
+
+impl From<HtmlElement> for ElementsOrCallback {
+    fn from(value: HtmlElement) -> Self {
+        Self::Elements(vec![value])
+    }
+}
+
+impl From<Vec<HtmlElement>> for ElementsOrCallback {
+    fn from(value: Vec<HtmlElement>) -> Self {
+        Self::Elements(value)
+    }
+}
+
+impl<F: Fn() -> Result<Vec<HtmlElement>, QueryError> + 'static> From<F> for ElementsOrCallback {
+    fn from(value: F) -> Self {
+        Self::Callback(Box::new(value))
+    }
 }
@@ -1,5 +1,349 @@
-use crate::types::WaitForOptions;
+use std::{
+    cell::RefCell,
+    future::{Future, poll_fn, ready},
+    pin::{Pin, pin},
+    rc::Rc,
+    task::{Context, Poll},
+};
 
-pub async fn wait_for<T>(callback: Box<dyn Fn() -> T>, _options: WaitForOptions) -> T {
-    callback()
+use js_sys::{Function, Promise};
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{AbortSignal, Element, MutationObserver, MutationObserverInit, window};
+
+use crate::{
+    config::get_config, error::QueryError, helpers::get_document, query_helpers::get_element_error,
+    types::WaitForOptions,
+};
+
+/// Resolve function of the pending check, shared between the interval timer and the mutation observer.
+type PendingCheck = Rc<RefCell<Option<Function>>>;
+
+/// Mutation observer which triggers the pending check and disconnects when dropped.
+struct CheckObserver {
+    observer: MutationObserver,
+    _callback: Closure<dyn FnMut()>,
+}
+
+impl CheckObserver {
+    fn new(
+        container: &Element,
+        options: &MutationObserverInit,
+        pending_check: PendingCheck,
+    ) -> Result<Self, QueryError> {
+        let callback = Closure::<dyn FnMut()>::new(move || {
+            if let Some(resolve) = pending_check.borrow_mut().take() {
+                resolve
+                    .call0(&JsValue::UNDEFINED)
+                    .expect("Resolve should be called.");
+            }
+        });
+
+        let observer = MutationObserver::new(callback.as_ref().unchecked_ref())
+            .map_err(QueryError::JsError)?;
+        observer
+            .observe_with_options(container, options)
+            .map_err(QueryError::JsError)?;
+
+        Ok(Self {
+            observer,
+            _callback: callback,
+        })
+    }
+}
+
+impl Drop for CheckObserver {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
+
+/// Future which resolves after `timeout` milliseconds, yielding to the browser event loop in the meantime.
+///
+/// If a pending check is given, it can also be resolved early by the mutation observer.
+/// The timeout is cleared when dropped.
+struct Timer {
+    future: JsFuture,
+    timeout_handle: i32,
+    pending_check: Option<PendingCheck>,
+}
+
+impl Timer {
+    fn new(timeout: i32, pending_check: Option<PendingCheck>) -> Self {
+        let mut timeout_handle = 0;
+        let promise = Promise::new(&mut |resolve, _reject| {
+            timeout_handle = window()
+                .expect("Window should exist.")
+                .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, timeout)
+                .expect("Timeout should be set.");
+
+            if let Some(pending_check) = &pending_check {
+                pending_check.replace(Some(resolve));
+            }
+        });
+
+        Self {
+            future: JsFuture::from(promise),
+            timeout_handle,
+            pending_check,
+        }
+    }
+}
+
+impl Future for Timer {
+    type Output = Result<(), QueryError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.future)
+            .poll(cx)
+            .map(|result| result.map(|_| ()).map_err(QueryError::JsError))
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        if let Some(pending_check) = &self.pending_check {
+            pending_check.take();
+        }
+
+        window()
+            .expect("Window should exist.")
+            .clear_timeout_with_handle(self.timeout_handle);
+    }
+}
+
+/// Future which resolves when the signal is aborted. The event listener is removed when dropped.
+struct AbortListener {
+    future: JsFuture,
+    signal: AbortSignal,
+    listener: Function,
+}
+
+impl AbortListener {
+    fn new(signal: AbortSignal) -> Self {
+        let mut listener = None;
+        let promise = Promise::new(&mut |resolve, _reject| {
+            signal
+                .add_event_listener_with_callback("abort", &resolve)
+                .expect("Event listener should be added.");
+            listener = Some(resolve);
+        });
+
+        Self {
+            future: JsFuture::from(promise),
+            signal,
+            listener: listener.expect("Promise executor should be called."),
+        }
+    }
+}
+
+impl Future for AbortListener {
+    type Output = QueryError;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.future)
+            .poll(cx)
+            .map(|_| QueryError::Aborted(self.signal.reason()))
+    }
+}
+
+impl Drop for AbortListener {
+    fn drop(&mut self) {
+        self.signal
+            .remove_event_listener_with_callback("abort", &self.listener)
+            .expect("Event listener should be removed.");
+    }
+}
+
+fn default_mutation_observer_init() -> MutationObserverInit {
+    let init = MutationObserverInit::new();
+    init.set_subtree(true);
+    init.set_child_list(true);
+    init.set_attributes(true);
+    init.set_character_data(true);
+    init
+}
+
+async fn _wait_for<T, F: Future<Output = Result<T, QueryError>>>(
+    callback: impl Fn() -> F,
+    options: WaitForOptions,
+) -> Result<T, QueryError> {
+    let container: Element = match options.container {
+        Some(container) => container.into(),
+        None => get_document()
+            .document_element()
+            .expect("Document element should exist."),
+    };
+    let config = get_config();
+    let timeout = options.timeout.unwrap_or(config.async_util_timeout);
+    let interval = options.interval.unwrap_or(50);
+    let mutation_observer_init = options
+        .mutation_observer_init
+        .unwrap_or_else(default_mutation_observer_init);
+
+    let on_timeout = |last_error: Option<QueryError>| {
+        let error = last_error
+            .unwrap_or_else(|| QueryError::Element("Timed out in `wait_for`.".to_owned()));
+
+        match &options.on_timeout {
+            Some(on_timeout) => on_timeout(error),
+            None => match error {
+                QueryError::Element(message) => get_element_error(Some(message), container.clone()),
+                error => error,
+            },
+        }
+    };
+
+    let signal = options.signal.clone();
+    if let Some(signal) = &signal
+        && signal.aborted()
+    {
+        return Err(QueryError::Aborted(signal.reason()));
+    }
+
+    // Dropping the checks when aborted clears their timers and disconnects the mutation observer.
+    let checks = async {
+        let mut last_error = None;
+
+        if config.clock.is_fake() {
+            let mut attempt = Some(Box::pin(callback()));
+            let mut elapsed = 0;
+
+            loop {
+                // A pending attempt is only polled, so it can make progress while the fake timers advance.
+                if let Some(pending_attempt) = attempt.as_mut()
+                    && let Poll::Ready(result) =
+                        poll_fn(|cx| Poll::Ready(pending_attempt.as_mut().poll(cx))).await
+                {
+                    match result {
+                        Ok(value) => return Ok(value),
+                        Err(error) => last_error = Some(error),
+                    }
+                    attempt = None;
+                }
+
+                if elapsed >= timeout {
+                    return Err(on_timeout(last_error));
+                }
+
+                // Always advance the clock, so an interval of zero or less can't stall the fake time.
+                let step = interval.min(timeout - elapsed).max(1);
+                (config.advance_timers_wrapper)(Box::pin({
+                    let clock = config.clock.clone();
+
+                    async move {
+                        clock.advance_timers_by(step);
+                    }
+                }))
+                .await;
+                elapsed += step;
+
+                // Let promises resolved by the timers settle before checking again.
+                JsFuture::from(Promise::resolve(&JsValue::UNDEFINED))
+                    .await
+                    .map_err(QueryError::JsError)?;
+
+                if attempt.is_none() {
+                    attempt = Some(Box::pin(callback()));
+                }
+            }
+        }
+
+        let start = config.clock.now();
+        let remaining = || f64::from(timeout) - (config.clock.now() - start);
+
+        let pending_check = PendingCheck::default();
+        let _observer =
+            CheckObserver::new(&container, &mutation_observer_init, pending_check.clone())?;
+
+        loop {
+            // Only one attempt runs at a time. Checks triggered by the interval or mutations while an attempt is
+            // pending are skipped, but the overall timeout still applies.
+            let mut attempt = pin!(callback());
+            let mut overall_timer = Timer::new(remaining().max(0.0).ceil() as i32, None);
+
+            let attempt_result = poll_fn(|cx| {
+                if let Poll::Ready(result) = attempt.as_mut().poll(cx) {
+                    return Poll::Ready(Some(result));
+                }
+                if Pin::new(&mut overall_timer).poll(cx).is_ready() {
+                    return Poll::Ready(None);
+                }
+                Poll::Pending
+            })
+            .await;
+
+            match attempt_result {
+                Some(Ok(value)) => return Ok(value),
+                Some(Err(error)) => last_error = Some(error),
+                None => return Err(on_timeout(last_error)),
+            }
+
+            let remaining = remaining();
+            if remaining <= 0.0 {
+                return Err(on_timeout(last_error));
+            }
+
+            // Don't wait past the overall timeout, so the final check happens on time.
+            Timer::new(
+                interval.min(remaining.ceil() as i32),
+                Some(pending_check.clone()),
+            )
+            .await?;
+        }
+    };
+
+    match signal {
+        Some(signal) => {
+            let mut checks = pin!(checks);
+            let mut abort_listener = AbortListener::new(signal);
+
+            poll_fn(|cx| {
+                if let Poll::Ready(result) = checks.as_mut().poll(cx) {
+                    return Poll::Ready(result);
+                }
+                Pin::new(&mut abort_listener).poll(cx).map(Err)
+            })
+            .await
+        }
+        None => checks.await,
+    }
+}
+
+async fn with_async_wrapper<T: 'static>(
+    future: impl Future<Output = Result<T, QueryError>> + 'static,
+) -> Result<T, QueryError> {
+    let result = Rc::new(RefCell::new(None));
+
+    (get_config().async_wrapper)(Box::pin({
+        let result = result.clone();
+
+        async move {
+            result.replace(Some(future.await));
+        }
+    }))
+    .await;
+
+    result.take().unwrap_or_else(|| {
+        Err(QueryError::Configuration(
+            "`async_wrapper` should await the future it is given.".to_owned(),
+        ))
+    })
+}
+
+pub async fn wait_for<T: 'static>(
+    callback: Box<dyn Fn() -> Result<T, QueryError>>,
+    options: WaitForOptions,
+) -> Result<T, QueryError> {
+    with_async_wrapper(_wait_for(move || ready(callback()), options)).await
+}
+
+/// Variant of [`wait_for`] for callbacks which need to await, e.g. nested `find_by` queries.
+///
+/// A new attempt is only started once the previous future has resolved.
+pub async fn wait_for_async<T: 'static, F: Future<Output = Result<T, QueryError>> + 'static>(
+    callback: Box<dyn Fn() -> F>,
+    options: WaitForOptions,
+) -> Result<T, QueryError> {
+    with_async_wrapper(_wait_for(callback, options)).await
 }
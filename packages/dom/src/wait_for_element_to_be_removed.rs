@@ -0,0 +1,72 @@
+use web_sys::HtmlElement;
+
+use crate::{
+    error::QueryError,
+    types::{ElementsOrCallback, WaitForElementToBeRemovedFn, WaitForOptions},
+    wait_for::wait_for,
+};
+
+fn is_removed(elements: &[HtmlElement]) -> bool {
+    elements.is_empty()
+}
+
+fn initial_check(elements: &[HtmlElement]) -> Result<(), QueryError> {
+    if is_removed(elements) {
+        Err(QueryError::Element(
+            "The element(s) given to `wait_for_element_to_be_removed` are already removed. \
+            `wait_for_element_to_be_removed` requires that the element(s) exist(s) before waiting for removal."
+                .to_owned(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+pub async fn wait_for_element_to_be_removed<C: Into<ElementsOrCallback>>(
+    callback: C,
+    options: WaitForOptions,
+) -> Result<(), QueryError> {
+    let callback: Box<WaitForElementToBeRemovedFn> = match callback.into() {
+        ElementsOrCallback::Elements(elements) => {
+            initial_check(&elements)?;
+
+            let get_remaining_elements = elements
+                .into_iter()
+                .map(|element| -> Box<dyn Fn() -> Option<HtmlElement>> {
+                    let Some(mut parent) = element.parent_element() else {
+                        return Box::new(|| None);
+                    };
+                    while let Some(parent_element) = parent.parent_element() {
+                        parent = parent_element;
+                    }
+
+                    Box::new(move || parent.contains(Some(&element)).then(|| element.clone()))
+                })
+                .collect::<Vec<_>>();
+
+            Box::new(move || {
+                Ok(get_remaining_elements
+                    .iter()
+                    .filter_map(|get_remaining_element| get_remaining_element())
+                    .collect())
+            })
+        }
+        ElementsOrCallback::Callback(callback) => callback,
+    };
+
+    initial_check(&callback()?)?;
+
+    wait_for(
+        Box::new(move || match callback() {
+            Ok(elements) if is_removed(&elements) => Ok(()),
+            Ok(_) => Err(QueryError::Element(
+                "Timed out in `wait_for_element_to_be_removed`.".to_owned(),
+            )),
+            // Elements which can no longer be found are removed.
+            Err(QueryError::Element(_)) => Ok(()),
+            Err(error) => Err(error),
+        }),
+        options,
+    )
+    .await
+}
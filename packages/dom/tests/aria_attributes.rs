@@ -5,7 +5,10 @@
 mod helpers;
 
 use indoc::indoc;
-use testing_library_dom::{AriaRole, ByRoleOptions, QueryError, SelectorMatcherOptions};
+use testing_library_dom::{
+    AriaRole, BoundQueries, ByRoleOptions, ByRoleOptionsValue, CheckedState, HasPopup, QueryError,
+    SelectorMatcherOptions,
+};
 use wasm_bindgen::JsCast;
 use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
 use web_sys::HtmlInputElement;
@@ -214,4 +217,842 @@ fn checked_true_false_does_not_match_element_in_indeterminate_state() {
     );
 }
 
+#[wasm_bindgen_test]
+fn checked_mixed_matches_elements_in_indeterminate_state() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(indoc! {"
+      <div>
+        <span role=\"checkbox\" aria-checked=\"mixed\">explicit mixed</span>
+        <input type=\"checkbox\" aria-label=\"indeterminate\" />
+        <input type=\"checkbox\" checked aria-label=\"checked\" />
+      </div>
+    "});
+
+    container_queries
+        .get_by_label_text("indeterminate", SelectorMatcherOptions::default())
+        .expect("Get should succeed.")
+        .unchecked_into::<HtmlInputElement>()
+        .set_indeterminate(true);
+
+    let mixed = container_queries
+        .get_all_by_role(
+            AriaRole::Checkbox,
+            ByRoleOptions::default().checked(CheckedState::Mixed),
+        )
+        .expect("Get should succeed.");
+
+    assert_eq!(2, mixed.len());
+    assert_eq!(Some("explicit mixed".to_owned()), mixed[0].text_content());
+    assert_eq!(
+        Some("indeterminate".to_owned()),
+        mixed[1].get_attribute("aria-label")
+    );
+}
+
+#[wasm_bindgen_test]
+fn pressed_true_false_mixed_matches_toggle_buttons() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(indoc! {"
+      <div>
+        <button aria-pressed=\"true\">pressed</button>
+        <button aria-pressed=\"false\">released</button>
+        <button aria-pressed=\"mixed\">mixed</button>
+      </div>
+    "});
+
+    for (state, name) in [
+        (CheckedState::True, "pressed"),
+        (CheckedState::False, "released"),
+        (CheckedState::Mixed, "mixed"),
+    ] {
+        assert_eq!(
+            Some(name.to_owned()),
+            container_queries
+                .get_by_role(AriaRole::Button, ByRoleOptions::default().pressed(state))
+                .expect("Get should succeed.")
+                .text_content()
+        );
+    }
+}
+
+/// Returns the accessible names of all elements with the role matching the options.
+fn names_by_role(
+    container_queries: &BoundQueries,
+    role: AriaRole,
+    options: ByRoleOptions,
+) -> Vec<String> {
+    container_queries
+        .query_all_by_role(role, options)
+        .expect("Query should succeed.")
+        .into_iter()
+        .map(|element| {
+            element
+                .get_attribute("aria-label")
+                .or_else(|| element.text_content())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+#[wasm_bindgen_test]
+fn disabled_throws_on_unsupported_roles() {
+    let RenderReturn {
+        container_queries, ..
+    } = render("<h1 aria-disabled=\"true\">Heading</h1>", None);
+
+    assert_eq!(
+        container_queries.get_by_role(AriaRole::Heading, ByRoleOptions::default().disabled(true)),
+        Err(QueryError::Unsupported(
+            "\"aria-disabled\" is not supported on role \"heading\".".to_owned()
+        ))
+    );
+}
+
+#[wasm_bindgen_test]
+fn disabled_true_false_matches_disabled_elements() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(indoc! {"
+      <div>
+        <button disabled>native</button>
+        <button aria-disabled=\"true\">explicit</button>
+        <div aria-disabled=\"true\"><button>inherited</button></div>
+        <fieldset disabled>
+          <legend><button>legend</button></legend>
+          <button>fieldset</button>
+        </fieldset>
+        <button>enabled</button>
+      </div>
+    "});
+
+    assert_eq!(
+        vec!["native", "explicit", "inherited", "fieldset"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Button,
+            ByRoleOptions::default().disabled(true)
+        )
+    );
+    assert_eq!(
+        vec!["legend", "enabled"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Button,
+            ByRoleOptions::default().disabled(false)
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn disabled_true_matches_options_in_disabled_optgroups() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(indoc! {"
+      <select multiple>
+        <optgroup label=\"Group\" disabled>
+          <option>grouped</option>
+        </optgroup>
+        <option>enabled</option>
+      </select>
+    "});
+
+    assert_eq!(
+        vec!["grouped"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Option,
+            ByRoleOptions::default().disabled(true)
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn invalid_true_false_matches_invalid_elements() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(indoc! {"
+      <div>
+        <input aria-label=\"invalid\" aria-invalid=\"true\" />
+        <input aria-label=\"spelling\" aria-invalid=\"spelling\" />
+        <input aria-label=\"valid\" aria-invalid=\"false\" />
+        <input aria-label=\"unspecified\" />
+      </div>
+    "});
+
+    assert_eq!(
+        vec!["invalid", "spelling"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Textbox,
+            ByRoleOptions::default().invalid(true)
+        )
+    );
+    assert_eq!(
+        vec!["valid", "unspecified"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Textbox,
+            ByRoleOptions::default().invalid(false)
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn required_true_false_matches_required_elements() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(indoc! {"
+      <div>
+        <input aria-label=\"native\" required />
+        <div role=\"textbox\" aria-label=\"explicit\" aria-required=\"true\"></div>
+        <input aria-label=\"optional\" />
+      </div>
+    "});
+
+    assert_eq!(
+        vec!["native", "explicit"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Textbox,
+            ByRoleOptions::default().required(true)
+        )
+    );
+    assert_eq!(
+        vec!["optional"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Textbox,
+            ByRoleOptions::default().required(false)
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn readonly_true_false_matches_readonly_elements() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(indoc! {"
+      <div>
+        <input aria-label=\"native\" readonly />
+        <textarea aria-label=\"textarea\" readonly></textarea>
+        <div role=\"textbox\" aria-label=\"explicit\" aria-readonly=\"true\"></div>
+        <input aria-label=\"editable\" />
+      </div>
+    "});
+
+    assert_eq!(
+        vec!["native", "textarea", "explicit"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Textbox,
+            ByRoleOptions::default().readonly(true)
+        )
+    );
+    assert_eq!(
+        vec!["editable"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Textbox,
+            ByRoleOptions::default().readonly(false)
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn multiselectable_true_false_matches_multiselectable_elements() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(indoc! {"
+      <div>
+        <select aria-label=\"native\" multiple><option>A</option></select>
+        <ul role=\"listbox\" aria-label=\"explicit\" aria-multiselectable=\"true\"></ul>
+        <ul role=\"listbox\" aria-label=\"single\"></ul>
+      </div>
+    "});
+
+    assert_eq!(
+        vec!["native", "explicit"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Listbox,
+            ByRoleOptions::default().multiselectable(true)
+        )
+    );
+    assert_eq!(
+        vec!["single"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Listbox,
+            ByRoleOptions::default().multiselectable(false)
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn haspopup_matches_elements_by_popup_type() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(indoc! {"
+      <div>
+        <button aria-haspopup=\"true\">true</button>
+        <button aria-haspopup=\"menu\">menu</button>
+        <button aria-haspopup=\"dialog\">dialog</button>
+        <button aria-haspopup=\"false\">false</button>
+        <button>unspecified</button>
+      </div>
+    "});
+
+    assert_eq!(
+        vec!["true", "menu", "dialog"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Button,
+            ByRoleOptions::default().haspopup(true)
+        )
+    );
+    assert_eq!(
+        vec!["true", "menu"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Button,
+            ByRoleOptions::default().haspopup(HasPopup::Menu)
+        )
+    );
+    assert_eq!(
+        vec!["dialog"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Button,
+            ByRoleOptions::default().haspopup(HasPopup::Dialog)
+        )
+    );
+    assert_eq!(
+        vec!["false", "unspecified"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Button,
+            ByRoleOptions::default().haspopup(false)
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn haspopup_listbox_matches_comboboxes_implicitly() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(indoc! {"
+      <div>
+        <select aria-label=\"implicit\"><option>A</option></select>
+        <div role=\"combobox\" aria-label=\"dialog\" aria-haspopup=\"dialog\"></div>
+      </div>
+    "});
+
+    assert_eq!(
+        vec!["implicit"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Combobox,
+            ByRoleOptions::default().haspopup(HasPopup::Listbox)
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn level_throws_on_unsupported_roles() {
+    let RenderReturn {
+        container_queries, ..
+    } = render("<button aria-level=\"1\">Button</button>", None);
+
+    assert_eq!(
+        container_queries.get_by_role(AriaRole::Button, ByRoleOptions::default().level(1)),
+        Err(QueryError::Unsupported(
+            "Role \"button\" cannot have \"level\" property.".to_owned()
+        ))
+    );
+}
+
+#[wasm_bindgen_test]
+fn level_matches_implicit_list_item_levels() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(indoc! {"
+      <ul>
+        <li aria-label=\"fruit\">
+          <ol>
+            <li aria-label=\"apple\"></li>
+            <li aria-label=\"explicit\" aria-level=\"5\"></li>
+          </ol>
+        </li>
+        <li aria-label=\"vegetables\"></li>
+      </ul>
+    "});
+
+    assert_eq!(
+        vec!["fruit", "vegetables"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Listitem,
+            ByRoleOptions::default().level(1)
+        )
+    );
+    assert_eq!(
+        vec!["apple"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Listitem,
+            ByRoleOptions::default().level(2)
+        )
+    );
+    assert_eq!(
+        vec!["explicit"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Listitem,
+            ByRoleOptions::default().level(5)
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn level_matches_implicit_tree_item_levels() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(indoc! {"
+      <ul role=\"tree\">
+        <li role=\"treeitem\" aria-label=\"root\">
+          <ul role=\"group\">
+            <li role=\"treeitem\" aria-label=\"child\">
+              <ul role=\"group\">
+                <li role=\"treeitem\" aria-label=\"grandchild\"></li>
+              </ul>
+            </li>
+          </ul>
+        </li>
+      </ul>
+    "});
+
+    for (level, name) in [(1, "root"), (2, "child"), (3, "grandchild")] {
+        assert_eq!(
+            vec![name],
+            names_by_role(
+                &container_queries,
+                AriaRole::Treeitem,
+                ByRoleOptions::default().level(level)
+            )
+        );
+    }
+}
+
+#[wasm_bindgen_test]
+fn level_matches_explicit_row_levels() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(indoc! {"
+      <div role=\"treegrid\">
+        <div role=\"row\" aria-level=\"1\" aria-label=\"parent\"></div>
+        <div role=\"row\" aria-level=\"2\" aria-label=\"child\"></div>
+      </div>
+    "});
+
+    assert_eq!(
+        vec!["child"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Row,
+            ByRoleOptions::default().level(2)
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn position_in_set_and_set_size_are_computed_from_siblings() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(indoc! {"
+      <div role=\"tablist\">
+        <button role=\"tab\">first</button>
+        <button role=\"tab\">second</button>
+        <span>Not a tab</span>
+        <button role=\"tab\" aria-selected=\"true\">third</button>
+        <button role=\"tab\">fourth</button>
+        <button role=\"tab\">fifth</button>
+      </div>
+    "});
+
+    assert_eq!(
+        vec!["third"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Tab,
+            ByRoleOptions::default()
+                .selected(true)
+                .position_in_set(3)
+                .set_size(5)
+        )
+    );
+    assert_eq!(
+        5,
+        names_by_role(
+            &container_queries,
+            AriaRole::Tab,
+            ByRoleOptions::default().set_size(5)
+        )
+        .len()
+    );
+}
+
+#[wasm_bindgen_test]
+fn position_in_set_and_set_size_prefer_explicit_values() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(indoc! {"
+      <ul role=\"listbox\">
+        <li role=\"option\" aria-posinset=\"8\" aria-setsize=\"20\">eighth</li>
+        <li role=\"option\" aria-posinset=\"9\" aria-setsize=\"-1\">ninth</li>
+      </ul>
+    "});
+
+    assert_eq!(
+        vec!["eighth"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Option,
+            ByRoleOptions::default().position_in_set(8).set_size(20)
+        )
+    );
+    assert!(
+        names_by_role(
+            &container_queries,
+            AriaRole::Option,
+            ByRoleOptions::default().set_size(2)
+        )
+        .is_empty()
+    );
+}
+
+#[wasm_bindgen_test]
+fn position_in_set_and_set_size_are_computed_per_tree_group() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(indoc! {"
+      <ul role=\"tree\">
+        <li role=\"treeitem\" aria-label=\"first root\">
+          <ul role=\"group\">
+            <li role=\"treeitem\" aria-label=\"only child\"></li>
+          </ul>
+        </li>
+        <li role=\"treeitem\" aria-label=\"second root\"></li>
+      </ul>
+    "});
+
+    assert_eq!(
+        vec!["only child"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Treeitem,
+            ByRoleOptions::default().set_size(1)
+        )
+    );
+    assert_eq!(
+        vec!["second root"],
+        names_by_role(
+            &container_queries,
+            AriaRole::Treeitem,
+            ByRoleOptions::default().position_in_set(2)
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn position_in_set_throws_on_unsupported_roles() {
+    let RenderReturn {
+        container_queries, ..
+    } = render("<button>Button</button>", None);
+
+    assert_eq!(
+        container_queries.get_by_role(
+            AriaRole::Button,
+            ByRoleOptions::default().position_in_set(1)
+        ),
+        Err(QueryError::Unsupported(
+            "\"aria-posinset\" is not supported on role \"button\".".to_owned()
+        ))
+    );
+    assert_eq!(
+        container_queries.get_by_role(AriaRole::Button, ByRoleOptions::default().set_size(1)),
+        Err(QueryError::Unsupported(
+            "\"aria-setsize\" is not supported on role \"button\".".to_owned()
+        ))
+    );
+}
+
+/// State filter which can be set on [`ByRoleOptions`], with the attribute values matching `true` and `false`.
+struct StateFilter {
+    attribute: &'static str,
+    values: [&'static str; 2],
+    apply: fn(ByRoleOptions, bool) -> ByRoleOptions,
+}
+
+const SELECTED: StateFilter = StateFilter {
+    attribute: "aria-selected",
+    values: ["true", "false"],
+    apply: |options, value| options.selected(value),
+};
+
+const BUSY: StateFilter = StateFilter {
+    attribute: "aria-busy",
+    values: ["true", "false"],
+    apply: |options, value| options.busy(value),
+};
+
+const CHECKED: StateFilter = StateFilter {
+    attribute: "aria-checked",
+    values: ["true", "false"],
+    apply: |options, value| options.checked(value),
+};
+
+const PRESSED: StateFilter = StateFilter {
+    attribute: "aria-pressed",
+    values: ["true", "false"],
+    apply: |options, value| options.pressed(value),
+};
+
+const CURRENT: StateFilter = StateFilter {
+    attribute: "aria-current",
+    values: ["page", "false"],
+    apply: |options, value| match value {
+        true => options.current("page"),
+        false => options.current(false),
+    },
+};
+
+const EXPANDED: StateFilter = StateFilter {
+    attribute: "aria-expanded",
+    values: ["true", "false"],
+    apply: |options, value| options.expanded(value),
+};
+
+const LEVEL: StateFilter = StateFilter {
+    attribute: "aria-level",
+    values: ["1", "2"],
+    apply: |options, value| options.level(if value { 1 } else { 2 }),
+};
+
+/// Updates the value options, keeping value filters which were applied before.
+fn with_value(
+    options: ByRoleOptions,
+    update: impl FnOnce(ByRoleOptionsValue) -> ByRoleOptionsValue,
+) -> ByRoleOptions {
+    let value = options.value.clone().unwrap_or_default();
+    options.value(update(value))
+}
+
+const VALUE: StateFilter = StateFilter {
+    attribute: "aria-valuenow",
+    values: ["1", "2"],
+    apply: |options, value| with_value(options, |v| v.now(if value { 1.0 } else { 2.0 })),
+};
+
+const VALUE_MIN: StateFilter = StateFilter {
+    attribute: "aria-valuemin",
+    values: ["0", "-1"],
+    apply: |options, value| with_value(options, |v| v.min(if value { 0.0 } else { -1.0 })),
+};
+
+const VALUE_MAX: StateFilter = StateFilter {
+    attribute: "aria-valuemax",
+    values: ["10", "20"],
+    apply: |options, value| with_value(options, |v| v.max(if value { 10.0 } else { 20.0 })),
+};
+
+const VALUE_TEXT: StateFilter = StateFilter {
+    attribute: "aria-valuetext",
+    values: ["one", "two"],
+    apply: |options, value| with_value(options, |v| v.text(if value { "one" } else { "two" })),
+};
+
+const DISABLED: StateFilter = StateFilter {
+    attribute: "aria-disabled",
+    values: ["true", "false"],
+    apply: |options, value| options.disabled(value),
+};
+
+const INVALID: StateFilter = StateFilter {
+    attribute: "aria-invalid",
+    values: ["true", "false"],
+    apply: |options, value| options.invalid(value),
+};
+
+const REQUIRED: StateFilter = StateFilter {
+    attribute: "aria-required",
+    values: ["true", "false"],
+    apply: |options, value| options.required(value),
+};
+
+const READONLY: StateFilter = StateFilter {
+    attribute: "aria-readonly",
+    values: ["true", "false"],
+    apply: |options, value| options.readonly(value),
+};
+
+const STATE_FILTERS: [StateFilter; 15] = [
+    SELECTED, BUSY, CHECKED, PRESSED, CURRENT, EXPANDED, LEVEL, VALUE, VALUE_MIN, VALUE_MAX,
+    VALUE_TEXT, DISABLED, INVALID, REQUIRED, READONLY,
+];
+
+/// Roles covering every state filter, see [`STATE_FILTERS`].
+const STATE_FILTER_ROLES: [AriaRole; 11] = [
+    AriaRole::Button,
+    AriaRole::Checkbox,
+    AriaRole::Heading,
+    AriaRole::Listitem,
+    AriaRole::Option,
+    AriaRole::Row,
+    AriaRole::Slider,
+    AriaRole::Spinbutton,
+    AriaRole::Tab,
+    AriaRole::Textbox,
+    AriaRole::Treeitem,
+];
+
+/// Whether the role supports both filters, i.e. the query doesn't return an unsupported error.
+fn supports_filters(role: AriaRole, first: &StateFilter, second: &StateFilter) -> bool {
+    let RenderReturn {
+        container_queries, ..
+    } = render("<div></div>", None);
+
+    let options = (second.apply)((first.apply)(ByRoleOptions::default(), true), true);
+
+    !matches!(
+        container_queries.query_all_by_role(role, options),
+        Err(QueryError::Unsupported(_))
+    )
+}
+
+/// Renders an element for every combination of both filters and asserts that each combination matches only its own
+/// element.
+fn assert_filters_are_combined(role: AriaRole, first: &StateFilter, second: &StateFilter) {
+    let elements = [true, false]
+        .into_iter()
+        .flat_map(|first_value| {
+            [true, false].into_iter().map(move |second_value| {
+                format!(
+                    "<div role=\"{role}\" {}=\"{}\" {}=\"{}\" aria-label=\"{first_value} {second_value}\"></div>",
+                    first.attribute,
+                    first.values[usize::from(!first_value)],
+                    second.attribute,
+                    second.values[usize::from(!second_value)],
+                )
+            })
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(&format!("<div>{elements}</div>"));
+
+    for first_value in [true, false] {
+        for second_value in [true, false] {
+            let options = (second.apply)(
+                (first.apply)(ByRoleOptions::default(), first_value),
+                second_value,
+            );
+
+            assert_eq!(
+                Some(format!("{first_value} {second_value}")),
+                container_queries
+                    .get_by_role(role, options)
+                    .expect("Get should succeed.")
+                    .get_attribute("aria-label"),
+                "{} {first_value} and {} {second_value} on role \"{role}\"",
+                first.attribute,
+                second.attribute,
+            );
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+fn state_filters_are_combined() {
+    // Every pair of filters is combined on every role supporting both. Pairs without such a role (e.g. `pressed` and
+    // `level`) are skipped, since the query returns an unsupported error for them instead.
+    let mut combined = vec![];
+    for (index, first) in STATE_FILTERS.iter().enumerate() {
+        for second in &STATE_FILTERS[index + 1..] {
+            for role in STATE_FILTER_ROLES {
+                if supports_filters(role, first, second) {
+                    assert_filters_are_combined(role, first, second);
+                    combined.push((role, first.attribute, second.attribute));
+                }
+            }
+        }
+    }
+
+    // Guard against the role list losing coverage of state combinations with specific roles.
+    for expected in [
+        (AriaRole::Row, "aria-selected", "aria-level"),
+        (AriaRole::Treeitem, "aria-selected", "aria-level"),
+        (AriaRole::Row, "aria-expanded", "aria-level"),
+        (AriaRole::Treeitem, "aria-expanded", "aria-level"),
+        (AriaRole::Treeitem, "aria-checked", "aria-level"),
+        (AriaRole::Slider, "aria-valuenow", "aria-valuetext"),
+        (AriaRole::Slider, "aria-valuemin", "aria-valuemax"),
+        (AriaRole::Slider, "aria-busy", "aria-valuemin"),
+        (AriaRole::Spinbutton, "aria-valuemax", "aria-readonly"),
+        (AriaRole::Textbox, "aria-invalid", "aria-required"),
+    ] {
+        assert!(
+            combined.contains(&expected),
+            "{} and {} should be combined on role \"{}\"",
+            expected.1,
+            expected.2,
+            expected.0
+        );
+    }
+}
+
+#[wasm_bindgen_test]
+fn combined_filters_throw_if_one_is_unsupported() {
+    let RenderReturn {
+        container_queries, ..
+    } = render("<button aria-pressed=\"true\">Button</button>", None);
+
+    assert_eq!(
+        container_queries.get_by_role(
+            AriaRole::Button,
+            ByRoleOptions::default().pressed(true).checked(true)
+        ),
+        Err(QueryError::Unsupported(
+            "\"aria-checked\" is not supported on role \"button\".".to_owned()
+        ))
+    );
+}
+
+#[wasm_bindgen_test]
+fn selected_and_expanded_match_tree_grid_rows() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(indoc! {"
+      <table role=\"treegrid\">
+        <tr role=\"row\" aria-selected=\"true\" aria-expanded=\"false\"><td>Collapsed</td></tr>
+        <tr role=\"row\" aria-selected=\"true\" aria-expanded=\"true\"><td>Expanded</td></tr>
+        <tr role=\"row\" aria-selected=\"false\" aria-expanded=\"true\"><td>Unselected</td></tr>
+      </table>
+    "});
+
+    assert_eq!(
+        Some("Expanded".to_owned()),
+        container_queries
+            .get_by_role(
+                AriaRole::Row,
+                ByRoleOptions::default().selected(true).expanded(true)
+            )
+            .expect("Get should succeed.")
+            .text_content()
+    );
+}
+
 // TODO: More tests.
@@ -0,0 +1,119 @@
+#![cfg(target_arch = "wasm32")]
+
+mod helpers;
+
+use std::sync::{Arc, LazyLock, Mutex};
+
+use mockall::automock;
+use testing_library_dom::{
+    ConfigFnOrPartial, PartialConfig, QueryError, SelectorMatcherOptions, WaitForOptions,
+    configure, wait_for,
+};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+use self::helpers::test_utils::{RenderReturn, render};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+static ORIGINAL_CONFIG: LazyLock<Arc<Mutex<PartialConfig>>> =
+    LazyLock::new(|| Arc::new(Mutex::new(PartialConfig::default())));
+
+fn before_each() {
+    configure(ConfigFnOrPartial::Fn(Box::new(|existing_config| {
+        // Grab the existing configuration so we can restore it at the end of the test.
+        let mut original_config = ORIGINAL_CONFIG
+            .lock()
+            .expect("Original config mutex should be acquired.");
+        *original_config = PartialConfig::from(existing_config);
+
+        // Don't change the existing config.
+        PartialConfig::default()
+    })));
+}
+
+fn after_each() {
+    let original_config = ORIGINAL_CONFIG
+        .lock()
+        .expect("Original config mutex should be acquired.");
+
+    configure(ConfigFnOrPartial::Partial((*original_config).clone()));
+}
+
+#[automock]
+trait AsyncWrapper {
+    fn before(&self);
+    fn after(&self);
+}
+
+fn configure_mock_async_wrapper() {
+    let mut mock = MockAsyncWrapper::new();
+    mock.expect_before().times(1).return_const(());
+    mock.expect_after().times(1).return_const(());
+    let mock = Arc::new(mock);
+
+    configure(ConfigFnOrPartial::Partial(
+        PartialConfig::default().async_wrapper(Arc::new(move |cb| {
+            let mock = mock.clone();
+
+            Box::pin(async move {
+                mock.before();
+                cb.await;
+                mock.after();
+            })
+        })),
+    ));
+}
+
+#[wasm_bindgen_test]
+async fn wait_for_calls_the_async_wrapper() {
+    before_each();
+
+    configure_mock_async_wrapper();
+
+    assert_eq!(
+        Ok(42),
+        wait_for(Box::new(|| Ok(42)), WaitForOptions::default()).await
+    );
+
+    after_each();
+}
+
+#[wasm_bindgen_test]
+async fn find_by_calls_the_async_wrapper() {
+    before_each();
+
+    configure_mock_async_wrapper();
+
+    let RenderReturn {
+        container_queries, ..
+    } = render("<span>Loaded</span>", None);
+
+    container_queries
+        .find_by_text(
+            "Loaded",
+            SelectorMatcherOptions::default(),
+            WaitForOptions::default(),
+        )
+        .await
+        .expect("Find should succeed.");
+
+    after_each();
+}
+
+#[wasm_bindgen_test]
+async fn wait_for_errors_if_the_async_wrapper_does_not_await_the_future() {
+    before_each();
+
+    configure(ConfigFnOrPartial::Partial(
+        PartialConfig::default().async_wrapper(Arc::new(|_cb| Box::pin(async {}))),
+    ));
+
+    assert_eq!(
+        Err(QueryError::Configuration(
+            "`async_wrapper` should await the future it is given.".to_owned()
+        )),
+        wait_for(Box::new(|| Ok(42)), WaitForOptions::default()).await
+    );
+
+    after_each();
+}
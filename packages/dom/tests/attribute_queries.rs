@@ -0,0 +1,173 @@
+#![cfg(target_arch = "wasm32")]
+
+mod helpers;
+
+use indoc::indoc;
+use regex::Regex;
+use testing_library_dom::{
+    MatcherOptions, QueryError, WaitForOptions, get_all_by_attribute, get_by_attribute,
+};
+use wasm_bindgen::{JsCast, closure::Closure};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::window;
+
+use self::helpers::test_utils::{RenderReturn, render};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn get_by_attribute_returns_the_matching_element() {
+    let RenderReturn {
+        container,
+        container_queries,
+        ..
+    } = render(
+        "<button data-cy=\"submit\">Submit</button><button data-cy=\"cancel\">Cancel</button>",
+        None,
+    );
+
+    assert_eq!(
+        Some("Submit".to_owned()),
+        get_by_attribute("data-cy", &container, "submit", MatcherOptions::default())
+            .expect("Get should succeed.")
+            .text_content()
+    );
+    assert_eq!(
+        2,
+        container_queries
+            .get_all_by_attribute(
+                "data-cy",
+                Regex::new("^(submit|cancel)$").expect("Regex should be valid."),
+                MatcherOptions::default()
+            )
+            .expect("Get should succeed.")
+            .len()
+    );
+}
+
+#[wasm_bindgen_test]
+fn get_by_attribute_throws_a_useful_error_message() {
+    let RenderReturn { container, .. } = render("<div data-cy=\"other\"></div>", None);
+
+    assert_eq!(
+        Err(QueryError::Element(
+            indoc! {"
+            Unable to find an element by: [data-cy=\"submit\"]
+
+            Ignored nodes: comments, script, style
+            <div>
+              <div
+                data-cy=\"other\"
+              />
+            </div>"}
+            .to_owned()
+        )),
+        get_all_by_attribute("data-cy", &container, "submit", MatcherOptions::default())
+    );
+    assert_eq!(
+        Err(QueryError::Element(
+            indoc! {"
+            Unable to find an element by: [data-cy=\"submit\"]
+
+            Ignored nodes: comments, script, style
+            <div>
+              <div
+                data-cy=\"other\"
+              />
+            </div>"}
+            .to_owned()
+        )),
+        get_by_attribute("data-cy", &container, "submit", MatcherOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn get_by_attribute_throws_if_multiple_elements_match() {
+    let RenderReturn {
+        container_queries, ..
+    } = render(
+        "<span data-cy=\"item\"></span><span data-cy=\"item\"></span>",
+        None,
+    );
+
+    assert_eq!(
+        Err(QueryError::Element(
+            indoc! {"
+            Found multiple elements by: [data-cy=\"item\"]
+
+            Here are the matching elements:
+
+            Ignored nodes: comments, script, style
+            <span
+              data-cy=\"item\"
+            />
+
+            Ignored nodes: comments, script, style
+            <span
+              data-cy=\"item\"
+            />
+
+            (If this is intentional, then use the `*_all_by_*` variant of the query (like `query_all_by_text`, `get_all_by_text`, or `find_all_by_text`)).
+
+            Ignored nodes: comments, script, style
+            <div>
+              <span
+                data-cy=\"item\"
+              />
+              <span
+                data-cy=\"item\"
+              />
+            </div>"}
+            .to_owned()
+        )),
+        container_queries.get_by_attribute("data-cy", "item", MatcherOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+async fn find_by_attribute_waits_for_the_element() {
+    let RenderReturn {
+        container,
+        container_queries,
+        ..
+    } = render("<div></div>", None);
+
+    window()
+        .expect("Window should exist.")
+        .set_timeout_with_callback_and_timeout_and_arguments_0(
+            Closure::once_into_js(move || {
+                container
+                    .set_inner_html("<span data-cy=\"item\"></span><span data-cy=\"item\"></span>")
+            })
+            .unchecked_ref(),
+            50,
+        )
+        .expect("Timeout should be set.");
+
+    assert_eq!(
+        2,
+        container_queries
+            .find_all_by_attribute(
+                "data-cy",
+                "item",
+                MatcherOptions::default(),
+                WaitForOptions::default()
+            )
+            .await
+            .expect("Find should succeed.")
+            .len()
+    );
+    assert!(
+        container_queries
+            .find_by_attribute(
+                "data-cy",
+                "missing",
+                MatcherOptions::default(),
+                WaitForOptions::default().timeout(100)
+            )
+            .await
+            .expect_err("Find should fail.")
+            .to_string()
+            .starts_with("Unable to find an element by: [data-cy=\"missing\"]")
+    );
+}
@@ -86,3 +86,18 @@ fn configure_passes_existing_config_out_to_config_function() {
 
     after_each(lock);
 }
+
+#[test]
+fn configure_overrides_the_async_util_timeout() {
+    let lock = before_each();
+
+    assert_eq!(1000, get_config().async_util_timeout);
+
+    configure(ConfigFnOrPartial::Partial(
+        PartialConfig::default().async_util_timeout(2000),
+    ));
+
+    assert_eq!(2000, get_config().async_util_timeout);
+
+    after_each(lock);
+}
@@ -0,0 +1,205 @@
+#![cfg(target_arch = "wasm32")]
+
+mod helpers;
+
+use indoc::indoc;
+use testing_library_dom::{
+    Matcher, MatcherOptions, QueryError, WaitForOptions, bound_queries, build_queries,
+    query_all_by_attribute, screen, within,
+};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::HtmlElement;
+
+use self::helpers::test_utils::{RenderReturn, render, render_into_document};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn _query_all_by_data_cy(
+    container: &HtmlElement,
+    id: Matcher,
+    options: MatcherOptions,
+) -> Result<Vec<HtmlElement>, QueryError> {
+    query_all_by_attribute("data-cy", container, id, options)
+}
+
+fn get_multiple_error(
+    _container: &HtmlElement,
+    id: Matcher,
+    _options: MatcherOptions,
+) -> Result<String, QueryError> {
+    Ok(format!(
+        "Found multiple elements with the data-cy attribute of: {id}"
+    ))
+}
+
+fn get_missing_error(
+    _container: &HtmlElement,
+    id: Matcher,
+    _options: MatcherOptions,
+) -> Result<String, QueryError> {
+    Ok(format!(
+        "Unable to find an element with the data-cy attribute of: {id}"
+    ))
+}
+
+build_queries!(
+    _query_all_by_data_cy,
+    get_multiple_error,
+    get_missing_error,
+    data_cy,
+    Matcher,
+    MatcherOptions
+);
+
+use internal::{
+    find_all_by_data_cy, find_by_data_cy, get_all_by_data_cy, get_by_data_cy, query_all_by_data_cy,
+    query_by_data_cy,
+};
+
+bound_queries!(
+    trait DataCyQueries,
+    (data_cy, Matcher, MatcherOptions),
+);
+
+#[wasm_bindgen_test]
+async fn custom_queries_build_the_full_query_family() {
+    let RenderReturn { container, .. } = render(
+        "<button data-cy=\"submit\">Submit</button><span data-cy=\"item\"></span><span data-cy=\"item\"></span>",
+        None,
+    );
+
+    assert_eq!(
+        "BUTTON",
+        get_by_data_cy(&container, "submit", MatcherOptions::default())
+            .expect("Get should succeed.")
+            .tag_name()
+    );
+    assert!(
+        query_by_data_cy(&container, "missing", MatcherOptions::default())
+            .expect("Query should succeed.")
+            .is_none()
+    );
+    assert_eq!(
+        2,
+        get_all_by_data_cy(&container, "item", MatcherOptions::default())
+            .expect("Get should succeed.")
+            .len()
+    );
+    assert_eq!(
+        2,
+        query_all_by_data_cy(&container, "item", MatcherOptions::default())
+            .expect("Query should succeed.")
+            .len()
+    );
+    assert!(
+        find_by_data_cy(
+            &container,
+            "submit",
+            MatcherOptions::default(),
+            WaitForOptions::default()
+        )
+        .await
+        .is_ok()
+    );
+    assert_eq!(
+        2,
+        find_all_by_data_cy(
+            &container,
+            "item",
+            MatcherOptions::default(),
+            WaitForOptions::default()
+        )
+        .await
+        .expect("Find should succeed.")
+        .len()
+    );
+}
+
+#[wasm_bindgen_test]
+fn custom_queries_use_the_given_error_messages() {
+    let RenderReturn { container, .. } = render(
+        "<span data-cy=\"item\"></span><span data-cy=\"item\"></span>",
+        None,
+    );
+
+    assert_eq!(
+        Err(QueryError::Element(
+            indoc! {"
+            Unable to find an element with the data-cy attribute of: missing
+
+            Ignored nodes: comments, script, style
+            <div>
+              <span
+                data-cy=\"item\"
+              />
+              <span
+                data-cy=\"item\"
+              />
+            </div>"}
+            .to_owned()
+        )),
+        get_by_data_cy(&container, "missing", MatcherOptions::default())
+    );
+    assert!(
+        get_by_data_cy(&container, "item", MatcherOptions::default())
+            .expect_err("Get should fail.")
+            .to_string()
+            .starts_with("Found multiple elements with the data-cy attribute of: item")
+    );
+}
+
+#[wasm_bindgen_test]
+async fn custom_queries_are_bound_to_within() {
+    let RenderReturn { container, .. } = render(
+        "<section data-cy=\"section\"><button data-cy=\"submit\">Submit</button></section><button data-cy=\"submit\">Other</button>",
+        None,
+    );
+    let section = get_by_data_cy(&container, "section", MatcherOptions::default())
+        .expect("Get should succeed.");
+
+    let queries = within(section);
+    assert_eq!(
+        Some("Submit".to_owned()),
+        queries
+            .get_by_data_cy("submit", MatcherOptions::default())
+            .expect("Get should succeed.")
+            .text_content()
+    );
+    assert_eq!(
+        1,
+        queries
+            .find_all_by_data_cy(
+                "submit",
+                MatcherOptions::default(),
+                WaitForOptions::default()
+            )
+            .await
+            .expect("Find should succeed.")
+            .len()
+    );
+}
+
+#[wasm_bindgen_test]
+async fn custom_queries_are_bound_to_screen() {
+    let RenderReturn { container, .. } =
+        render_into_document("<button data-cy=\"submit\">Submit</button>");
+
+    assert!(
+        screen()
+            .query_by_data_cy("submit", MatcherOptions::default())
+            .expect("Query should succeed.")
+            .is_some()
+    );
+    assert!(
+        screen()
+            .find_by_data_cy(
+                "submit",
+                MatcherOptions::default(),
+                WaitForOptions::default()
+            )
+            .await
+            .is_ok()
+    );
+
+    container.set_inner_html("");
+}
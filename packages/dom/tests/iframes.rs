@@ -0,0 +1,372 @@
+#![cfg(target_arch = "wasm32")]
+
+mod helpers;
+
+use std::sync::{Arc, LazyLock, Mutex};
+
+use indoc::indoc;
+use testing_library_dom::{
+    AriaRole, ByRoleOptions, ConfigFnOrPartial, MatcherOptions, PartialConfig, QueryError,
+    SelectorMatcherOptions, configure, pretty_dom, within,
+};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::{HtmlElement, HtmlIFrameElement};
+
+use self::helpers::test_utils::{RenderReturn, render_into_document_div};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+static ORIGINAL_CONFIG: LazyLock<Arc<Mutex<PartialConfig>>> =
+    LazyLock::new(|| Arc::new(Mutex::new(PartialConfig::default())));
+
+fn before_each() {
+    configure(ConfigFnOrPartial::Fn(Box::new(|existing_config| {
+        // Grab the existing configuration so we can restore it at the end of the test.
+        let mut original_config = ORIGINAL_CONFIG
+            .lock()
+            .expect("Original config mutex should be acquired.");
+        *original_config = PartialConfig::from(existing_config);
+
+        // Don't change the existing config.
+        PartialConfig::default()
+    })));
+}
+
+fn after_each() {
+    let original_config = ORIGINAL_CONFIG
+        .lock()
+        .expect("Original config mutex should be acquired.");
+
+    configure(ConfigFnOrPartial::Partial((*original_config).clone()));
+}
+
+/// Renders a same-origin frame and sets the body of its document to `html`.
+fn render_iframe(html: &str) -> (RenderReturn, HtmlIFrameElement) {
+    let result = render_into_document_div("<iframe></iframe>");
+
+    let iframe = result
+        .container
+        .query_selector("iframe")
+        .expect("Selector should be valid.")
+        .expect("Frame should exist.")
+        .unchecked_into::<HtmlIFrameElement>();
+    iframe
+        .content_document()
+        .expect("Frame should have content document.")
+        .body()
+        .expect("Body should exist.")
+        .set_inner_html(html);
+
+    (result, iframe)
+}
+
+#[wasm_bindgen_test]
+fn queries_do_not_traverse_iframes_by_default() {
+    let (
+        RenderReturn {
+            container_queries, ..
+        },
+        _,
+    ) = render_iframe("<button>Save</button>");
+
+    assert_eq!(
+        Ok(None),
+        container_queries.query_by_role(AriaRole::Button, ByRoleOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn queries_traverse_iframes_with_option() {
+    let (
+        RenderReturn {
+            container_queries, ..
+        },
+        _,
+    ) = render_iframe("<button data-testid=\"save\">Save</button>");
+
+    assert!(
+        container_queries
+            .get_by_role(
+                AriaRole::Button,
+                ByRoleOptions::default().traverse_iframes(true).name("Save")
+            )
+            .is_ok()
+    );
+    assert!(
+        container_queries
+            .get_by_text(
+                "Save",
+                SelectorMatcherOptions::default().traverse_iframes(true)
+            )
+            .is_ok()
+    );
+    assert!(
+        container_queries
+            .get_by_test_id("save", MatcherOptions::default().traverse_iframes(true))
+            .is_ok()
+    );
+}
+
+#[wasm_bindgen_test]
+fn config_enables_iframe_traversal_for_all_queries() {
+    let (
+        RenderReturn {
+            container_queries, ..
+        },
+        _,
+    ) = render_iframe("<button>Save</button>");
+
+    before_each();
+    configure(ConfigFnOrPartial::Partial(
+        PartialConfig::default().traverse_iframes(true),
+    ));
+
+    let result = container_queries.query_by_role(AriaRole::Button, ByRoleOptions::default());
+
+    after_each();
+
+    assert!(result.is_ok_and(|element| element.is_some()));
+}
+
+#[wasm_bindgen_test]
+fn within_iframe_targets_its_body() {
+    let (_, iframe) = render_iframe("<button>Save</button>");
+
+    let queries = within(iframe.unchecked_into::<HtmlElement>());
+
+    assert_eq!("BODY", queries.element().tag_name());
+    assert!(
+        queries
+            .get_by_role(AriaRole::Button, ByRoleOptions::default().name("Save"))
+            .is_ok()
+    );
+}
+
+#[wasm_bindgen_test]
+fn is_inaccessible_uses_the_styles_of_the_frame() {
+    let (_, iframe) = render_iframe(indoc! {"
+        <style>.hidden { display: none; }</style>
+        <button class=\"hidden\">Hidden</button>
+        <button>Visible</button>
+    "});
+    let queries = within(iframe.unchecked_into::<HtmlElement>());
+
+    let buttons = queries
+        .query_all_by_role(AriaRole::Button, ByRoleOptions::default())
+        .expect("Query should succeed.");
+    assert_eq!(
+        vec![Some("Visible".to_owned())],
+        buttons
+            .iter()
+            .map(|button| button.text_content())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[wasm_bindgen_test]
+fn hidden_iframes_make_their_content_inaccessible() {
+    let (
+        RenderReturn {
+            container_queries, ..
+        },
+        iframe,
+    ) = render_iframe("<button>Save</button>");
+    iframe
+        .set_attribute("hidden", "")
+        .expect("Attribute should be set.");
+
+    assert_eq!(
+        Ok(None),
+        container_queries.query_by_role(
+            AriaRole::Button,
+            ByRoleOptions::default().traverse_iframes(true)
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn by_role_computes_implicit_roles_and_states_in_iframes() {
+    let (
+        RenderReturn {
+            container_queries, ..
+        },
+        _,
+    ) = render_iframe(indoc! {"
+        <input type=\"text\" aria-label=\"Search\" />
+        <input type=\"checkbox\" aria-label=\"Checked\" checked />
+        <input type=\"checkbox\" aria-label=\"Unchecked\" />
+    "});
+
+    assert!(
+        container_queries
+            .get_by_role(
+                AriaRole::Textbox,
+                ByRoleOptions::default()
+                    .traverse_iframes(true)
+                    .name("Search")
+            )
+            .is_ok()
+    );
+    assert_eq!(
+        Some("Checked".to_owned()),
+        container_queries
+            .get_by_role(
+                AriaRole::Checkbox,
+                ByRoleOptions::default()
+                    .traverse_iframes(true)
+                    .checked(true)
+            )
+            .expect("Get should succeed.")
+            .get_attribute("aria-label")
+    );
+    assert_eq!(
+        Some("Unchecked".to_owned()),
+        container_queries
+            .get_by_role(
+                AriaRole::Checkbox,
+                ByRoleOptions::default()
+                    .traverse_iframes(true)
+                    .checked(false)
+            )
+            .expect("Get should succeed.")
+            .get_attribute("aria-label")
+    );
+}
+
+#[wasm_bindgen_test]
+fn by_label_text_associates_labels_in_iframes() {
+    let (
+        RenderReturn {
+            container_queries, ..
+        },
+        _,
+    ) = render_iframe(indoc! {"
+        <label for=\"username\">Username</label>
+        <input id=\"username\" />
+        <label>Password <input type=\"password\" /></label>
+    "});
+
+    assert_eq!(
+        Some("username".to_owned()),
+        container_queries
+            .get_by_label_text(
+                "Username",
+                SelectorMatcherOptions::default().traverse_iframes(true)
+            )
+            .expect("Get should succeed.")
+            .get_attribute("id")
+    );
+    assert_eq!(
+        Some("password".to_owned()),
+        container_queries
+            .get_by_label_text(
+                "Password",
+                SelectorMatcherOptions::default().traverse_iframes(true)
+            )
+            .expect("Get should succeed.")
+            .get_attribute("type")
+    );
+}
+
+#[wasm_bindgen_test]
+fn pretty_dom_does_not_print_frame_contents_by_default() {
+    let (RenderReturn { container, .. }, _) = render_iframe("<button>Save</button>");
+
+    assert_eq!(
+        indoc! {"
+        <div>
+          <iframe />
+        </div>"},
+        pretty_dom(Some(container.into()), None)
+    );
+}
+
+#[wasm_bindgen_test]
+fn pretty_dom_prints_frame_contents_when_traversing_iframes() {
+    let (RenderReturn { container, .. }, _) = render_iframe("<button>Save</button>");
+
+    before_each();
+    configure(ConfigFnOrPartial::Partial(
+        PartialConfig::default().traverse_iframes(true),
+    ));
+
+    let printed = pretty_dom(Some(container.into()), None);
+
+    after_each();
+
+    assert_eq!(
+        indoc! {"
+        <div>
+          <iframe>
+            #document
+              <html>
+                <head />
+                <body>
+                  <button>
+                    Save
+                  </button>
+                </body>
+              </html>
+          </iframe>
+        </div>"},
+        printed
+    );
+}
+
+#[wasm_bindgen_test]
+fn errors_print_frame_contents_when_the_query_traverses_iframes() {
+    let (
+        RenderReturn {
+            container_queries, ..
+        },
+        _,
+    ) = render_iframe("<button>Save</button>");
+
+    let Err(QueryError::Element(message)) = container_queries.get_by_text(
+        "Missing",
+        SelectorMatcherOptions::default().traverse_iframes(true),
+    ) else {
+        panic!("Get should fail with an element error.");
+    };
+
+    assert!(
+        message.ends_with(indoc! {"
+        <div>
+          <iframe>
+            #document
+              <html>
+                <head />
+                <body>
+                  <button>
+                    Save
+                  </button>
+                </body>
+              </html>
+          </iframe>
+        </div>"}),
+        "{message}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn missing_role_errors_list_roles_in_frames_when_the_query_traverses_iframes() {
+    let (
+        RenderReturn {
+            container_queries, ..
+        },
+        _,
+    ) = render_iframe("<button>Save</button>");
+
+    let Err(QueryError::Element(message)) = container_queries.get_by_role(
+        AriaRole::Link,
+        ByRoleOptions::default().traverse_iframes(true),
+    ) else {
+        panic!("Get should fail with an element error.");
+    };
+
+    assert!(
+        message.contains("Here are the accessible roles:\n\n  button:\n\n  Name \"Save\":"),
+        "{message}"
+    );
+}
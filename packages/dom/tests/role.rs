@@ -0,0 +1,271 @@
+#![cfg(target_arch = "wasm32")]
+
+mod helpers;
+
+use std::rc::Rc;
+
+use indoc::indoc;
+use regex::Regex;
+use testing_library_dom::{
+    AriaRole, ByRoleMatcherFunction, ByRoleOptions, MatcherFunction, QueryError,
+};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::Element;
+
+use self::helpers::test_utils::{RenderReturn, render};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn get_throws_a_useful_error_message() {
+    let RenderReturn {
+        container_queries, ..
+    } = render("<h1>Hi</h1>", None);
+
+    assert_eq!(
+        Err(QueryError::Element(
+            indoc! {"
+            Unable to find an accessible element with the role \"article\"
+
+            Here are the accessible roles:
+
+              heading:
+
+              Name \"Hi\":
+              <h1 />
+
+              --------------------------------------------------
+
+            Ignored nodes: comments, script, style
+            <div>
+              <h1>
+                Hi
+              </h1>
+            </div>"}
+            .to_owned()
+        )),
+        container_queries.get_by_role(AriaRole::Article, ByRoleOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn get_lists_available_roles_when_hidden_is_set() {
+    let RenderReturn {
+        container_queries, ..
+    } = render("<div />", None);
+
+    assert_eq!(
+        Err(QueryError::Element(
+            indoc! {"
+            Unable to find an element with the role \"article\"
+
+            There are no available roles.
+
+            Ignored nodes: comments, script, style
+            <div>
+              <div />
+            </div>"}
+            .to_owned()
+        )),
+        container_queries.get_by_role(AriaRole::Article, ByRoleOptions::default().hidden(true))
+    );
+}
+
+#[wasm_bindgen_test]
+fn get_includes_name_hints_in_the_error_message() {
+    let RenderReturn {
+        container_queries, ..
+    } = render("<div />", None);
+
+    let error_message = |options: ByRoleOptions| {
+        container_queries
+            .get_by_role(AriaRole::Button, options)
+            .expect_err("Get should fail.")
+            .to_string()
+            .lines()
+            .next()
+            .expect("Error should have a first line.")
+            .to_owned()
+    };
+
+    assert_eq!(
+        "Unable to find an accessible element with the role \"button\" and name \"Submit\"",
+        error_message(ByRoleOptions::default().name("Submit"))
+    );
+    assert_eq!(
+        "Unable to find an accessible element with the role \"button\" and name `^Sub`",
+        error_message(
+            ByRoleOptions::default().name(Regex::new("^Sub").expect("Regex should be valid."))
+        )
+    );
+    assert_eq!(
+        "Unable to find an accessible element with the role \"button\" and name `Fn`",
+        error_message(
+            ByRoleOptions::default()
+                .name(Rc::new(|_: String, _: Option<&Element>| false) as Rc<MatcherFunction>)
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn get_includes_descriptions_in_the_error_message() {
+    let RenderReturn {
+        container_queries, ..
+    } = render(
+        "<button aria-describedby=\"description\">Submit</button><div id=\"description\">Submit this form</div>",
+        None,
+    );
+
+    assert_eq!(
+        Err(QueryError::Element(
+            indoc! {"
+            Unable to find an accessible element with the role \"button\" and name \"Submit\" and description \"Not a description\"
+
+            Here are the accessible roles:
+
+              button:
+
+              Name \"Submit\":
+              Description \"Submit this form\":
+              <button
+                aria-describedby=\"description\"
+              />
+
+              --------------------------------------------------
+
+            Ignored nodes: comments, script, style
+            <div>
+              <button
+                aria-describedby=\"description\"
+              >
+                Submit
+              </button>
+              <div
+                id=\"description\"
+              >
+                Submit this form
+              </div>
+            </div>"}
+            .to_owned()
+        )),
+        container_queries.get_by_role(
+            AriaRole::Button,
+            ByRoleOptions::default()
+                .name("Submit")
+                .description("Not a description")
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn can_query_any_of_multiple_roles() {
+    let RenderReturn {
+        container_queries, ..
+    } = render(
+        "<button>Save</button><a href=\"/\">Home</a><div role=\"link\">Explicit</div><h1>Title</h1>",
+        None,
+    );
+
+    assert_eq!(
+        vec![
+            Some("Save".to_owned()),
+            Some("Home".to_owned()),
+            Some("Explicit".to_owned())
+        ],
+        container_queries
+            .get_all_by_role([AriaRole::Button, AriaRole::Link], ByRoleOptions::default())
+            .expect("Get should succeed.")
+            .into_iter()
+            .map(|element| element.text_content())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[wasm_bindgen_test]
+fn empty_role_lists_are_rejected() {
+    let RenderReturn {
+        container_queries, ..
+    } = render("<button>Save</button>", None);
+
+    assert_eq!(
+        Err(QueryError::Unsupported(
+            "At least one role is required to query by roles.".to_owned()
+        )),
+        container_queries.query_all_by_role(Vec::<AriaRole>::new(), ByRoleOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn can_query_by_role_string() {
+    let RenderReturn {
+        container_queries, ..
+    } = render("<button>Save</button>", None);
+
+    assert_eq!(
+        Some("Save".to_owned()),
+        container_queries
+            .get_by_role("button", ByRoleOptions::default())
+            .expect("Get should succeed.")
+            .text_content()
+    );
+}
+
+#[wasm_bindgen_test]
+fn role_strings_have_to_be_concrete_aria_roles() {
+    let RenderReturn {
+        container_queries, ..
+    } = render("<button>Save</button>", None);
+
+    assert_eq!(
+        Err(QueryError::Unsupported(
+            "\"buton\" is not a valid ARIA role.".to_owned()
+        )),
+        container_queries.get_by_role("buton", ByRoleOptions::default())
+    );
+    assert_eq!(
+        Err(QueryError::Unsupported(
+            "\"widget\" is an abstract role and cannot be queried. Query one of its concrete subclass roles instead."
+                .to_owned()
+        )),
+        container_queries.get_by_role("widget", ByRoleOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn can_query_roles_matching_a_function() {
+    let RenderReturn {
+        container_queries, ..
+    } = render(
+        "<h1>Title</h1><div role=\"heading\" aria-level=\"2\">Subtitle</div><button>Save</button>",
+        None,
+    );
+
+    let is_heading: Rc<ByRoleMatcherFunction> = Rc::new(|role, _| role == AriaRole::Heading);
+
+    assert_eq!(
+        vec![Some("Title".to_owned()), Some("Subtitle".to_owned())],
+        container_queries
+            .get_all_by_role(is_heading, ByRoleOptions::default())
+            .expect("Get should succeed.")
+            .into_iter()
+            .map(|element| element.text_content())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[wasm_bindgen_test]
+fn state_filters_have_to_be_supported_by_all_roles() {
+    let RenderReturn {
+        container_queries, ..
+    } = render("<button aria-pressed=\"true\">Save</button>", None);
+
+    assert_eq!(
+        Err(QueryError::Unsupported(
+            "\"aria-pressed\" is not supported on role \"link\".".to_owned()
+        )),
+        container_queries.get_by_role(
+            [AriaRole::Button, AriaRole::Link],
+            ByRoleOptions::default().pressed(true)
+        )
+    );
+}
@@ -0,0 +1,339 @@
+#![cfg(target_arch = "wasm32")]
+
+mod helpers;
+
+use std::sync::{Arc, LazyLock, Mutex};
+
+use indoc::indoc;
+use testing_library_dom::{
+    AriaRole, ByRoleOptions, ConfigFnOrPartial, MatcherOptions, PartialConfig, QueryError,
+    SelectorMatcherOptions, configure, pretty_dom,
+};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::{Element, HtmlElement, ShadowRoot, ShadowRootInit, ShadowRootMode};
+
+use self::helpers::test_utils::{RenderReturn, render, render_into_document_div};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+static ORIGINAL_CONFIG: LazyLock<Arc<Mutex<PartialConfig>>> =
+    LazyLock::new(|| Arc::new(Mutex::new(PartialConfig::default())));
+
+fn before_each() {
+    configure(ConfigFnOrPartial::Fn(Box::new(|existing_config| {
+        // Grab the existing configuration so we can restore it at the end of the test.
+        let mut original_config = ORIGINAL_CONFIG
+            .lock()
+            .expect("Original config mutex should be acquired.");
+        *original_config = PartialConfig::from(existing_config);
+
+        // Don't change the existing config.
+        PartialConfig::default()
+    })));
+}
+
+fn after_each() {
+    let original_config = ORIGINAL_CONFIG
+        .lock()
+        .expect("Original config mutex should be acquired.");
+
+    configure(ConfigFnOrPartial::Partial((*original_config).clone()));
+}
+
+fn attach_shadow(host: &Element, html: &str) -> ShadowRoot {
+    let shadow_root = host
+        .attach_shadow(&ShadowRootInit::new(ShadowRootMode::Open))
+        .expect("Shadow root should be attached.");
+    shadow_root.set_inner_html(html);
+    shadow_root
+}
+
+fn query_host(container: &HtmlElement, selector: &str) -> Element {
+    container
+        .query_selector(selector)
+        .expect("Selector should be valid.")
+        .expect("Host should exist.")
+}
+
+/// Renders `<my-button>Save</my-button>` with a shadow tree slotting the text into a button.
+fn render_button() -> RenderReturn {
+    let result = render_into_document_div("<my-button>Save</my-button>");
+    attach_shadow(
+        &query_host(&result.container, "my-button"),
+        "<button><slot></slot></button>",
+    );
+    result
+}
+
+#[wasm_bindgen_test]
+fn queries_do_not_pierce_shadow_roots_by_default() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_button();
+
+    assert_eq!(
+        Ok(None),
+        container_queries.query_by_role(AriaRole::Button, ByRoleOptions::default())
+    );
+}
+
+#[wasm_bindgen_test]
+fn by_role_pierces_shadow_roots_with_option() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_button();
+
+    let button = container_queries
+        .get_by_role(
+            AriaRole::Button,
+            ByRoleOptions::default()
+                .pierce_shadow_dom(true)
+                .name("Save"),
+        )
+        .expect("Get should succeed.");
+
+    assert_eq!("BUTTON", button.tag_name());
+}
+
+#[wasm_bindgen_test]
+fn by_text_uses_slot_assignment_with_option() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_button();
+
+    let button = container_queries
+        .get_by_text(
+            "Save",
+            SelectorMatcherOptions::default().pierce_shadow_dom(true),
+        )
+        .expect("Get should succeed.");
+
+    assert_eq!("BUTTON", button.tag_name());
+}
+
+#[wasm_bindgen_test]
+fn by_text_uses_slot_fallback_content() {
+    let RenderReturn {
+        container,
+        container_queries,
+        ..
+    } = render("<my-dialog></my-dialog>", None);
+    attach_shadow(
+        &query_host(&container, "my-dialog"),
+        "<p><slot>No content</slot></p>",
+    );
+
+    let paragraph = container_queries
+        .get_by_text(
+            "No content",
+            SelectorMatcherOptions::default().pierce_shadow_dom(true),
+        )
+        .expect("Get should succeed.");
+
+    assert_eq!("P", paragraph.tag_name());
+}
+
+#[wasm_bindgen_test]
+fn attribute_queries_pierce_nested_shadow_roots() {
+    let RenderReturn {
+        container,
+        container_queries,
+        ..
+    } = render("<outer-element></outer-element>", None);
+    let outer = attach_shadow(
+        &query_host(&container, "outer-element"),
+        "<inner-element></inner-element>",
+    );
+    attach_shadow(
+        &outer
+            .query_selector("inner-element")
+            .expect("Selector should be valid.")
+            .expect("Host should exist."),
+        "<input data-testid=\"nested\" placeholder=\"Search\" />",
+    );
+
+    assert_eq!(
+        Ok(None),
+        container_queries.query_by_test_id("nested", MatcherOptions::default())
+    );
+    assert!(
+        container_queries
+            .get_by_test_id("nested", MatcherOptions::default().pierce_shadow_dom(true))
+            .is_ok()
+    );
+    assert!(
+        container_queries
+            .get_by_placeholder_text("Search", MatcherOptions::default().pierce_shadow_dom(true))
+            .is_ok()
+    );
+}
+
+#[wasm_bindgen_test]
+fn config_enables_piercing_for_all_queries() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_button();
+
+    before_each();
+    configure(ConfigFnOrPartial::Partial(
+        PartialConfig::default().pierce_shadow_dom(true),
+    ));
+
+    let by_role = container_queries
+        .query_by_role(AriaRole::Button, ByRoleOptions::default())
+        .map(|element| element.map(|element| element.tag_name()));
+    let opted_out = container_queries.query_by_role(
+        AriaRole::Button,
+        ByRoleOptions::default().pierce_shadow_dom(false),
+    );
+
+    after_each();
+
+    assert_eq!(Ok(Some("BUTTON".to_owned())), by_role);
+    assert_eq!(Ok(None), opted_out);
+}
+
+#[wasm_bindgen_test]
+fn hidden_hosts_make_shadow_content_inaccessible() {
+    let RenderReturn {
+        container,
+        container_queries,
+        ..
+    } = render_into_document_div("<my-button hidden></my-button>");
+    attach_shadow(
+        &query_host(&container, "my-button"),
+        "<button>Hidden</button>",
+    );
+
+    assert_eq!(
+        Ok(None),
+        container_queries.query_by_role(
+            AriaRole::Button,
+            ByRoleOptions::default().pierce_shadow_dom(true)
+        )
+    );
+    assert!(
+        container_queries
+            .get_by_role(
+                AriaRole::Button,
+                ByRoleOptions::default()
+                    .pierce_shadow_dom(true)
+                    .hidden(true)
+            )
+            .is_ok()
+    );
+}
+
+#[wasm_bindgen_test]
+fn disabled_hosts_disable_shadow_content() {
+    let RenderReturn {
+        container,
+        container_queries,
+        ..
+    } = render_into_document_div("<my-button aria-disabled=\"true\"></my-button>");
+    attach_shadow(
+        &query_host(&container, "my-button"),
+        "<button>Disabled</button>",
+    );
+
+    assert!(
+        container_queries
+            .get_by_role(
+                AriaRole::Button,
+                ByRoleOptions::default()
+                    .pierce_shadow_dom(true)
+                    .disabled(true)
+            )
+            .is_ok()
+    );
+}
+
+#[wasm_bindgen_test]
+fn pretty_dom_does_not_print_shadow_roots_by_default() {
+    let RenderReturn { container, .. } = render_button();
+
+    assert_eq!(
+        indoc! {"
+        <div>
+          <my-button>
+            Save
+          </my-button>
+        </div>"},
+        pretty_dom(Some(container.into()), None)
+    );
+}
+
+#[wasm_bindgen_test]
+fn pretty_dom_prints_shadow_roots_when_piercing() {
+    let RenderReturn { container, .. } = render_button();
+
+    before_each();
+    configure(ConfigFnOrPartial::Partial(
+        PartialConfig::default().pierce_shadow_dom(true),
+    ));
+
+    let printed = pretty_dom(Some(container.into()), None);
+
+    after_each();
+
+    assert_eq!(
+        indoc! {"
+        <div>
+          <my-button>
+            #shadow-root
+              <button>
+                <slot />
+              </button>
+            Save
+          </my-button>
+        </div>"},
+        printed
+    );
+}
+
+#[wasm_bindgen_test]
+fn errors_print_shadow_roots_when_the_query_pierces() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_button();
+
+    let Err(QueryError::Element(message)) = container_queries.get_by_text(
+        "Missing",
+        SelectorMatcherOptions::default().pierce_shadow_dom(true),
+    ) else {
+        panic!("Get should fail with an element error.");
+    };
+
+    assert!(
+        message.ends_with(indoc! {"
+        <div>
+          <my-button>
+            #shadow-root
+              <button>
+                <slot />
+              </button>
+            Save
+          </my-button>
+        </div>"}),
+        "{message}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn missing_role_errors_list_roles_in_shadow_roots_when_the_query_pierces() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_button();
+
+    let Err(QueryError::Element(message)) = container_queries.get_by_role(
+        AriaRole::Link,
+        ByRoleOptions::default().pierce_shadow_dom(true),
+    ) else {
+        panic!("Get should fail with an element error.");
+    };
+
+    assert!(
+        message.contains("Here are the accessible roles:\n\n  button:"),
+        "{message}"
+    );
+}
@@ -0,0 +1,337 @@
+#![cfg(target_arch = "wasm32")]
+
+mod helpers;
+
+use std::sync::{Arc, LazyLock, Mutex};
+
+use testing_library_dom::{
+    AriaRole, ByRoleMatcher, ByRoleOptions, ConfigFnOrPartial, Matcher, MatcherOptions, Method,
+    PartialConfig, QueryError, Suggestion, SuggestionMatcher, SuggestionOptions, Variant,
+    configure, get_suggested_query,
+};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::HtmlElement;
+
+use self::helpers::test_utils::{RenderReturn, render_into_document_div};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+static ORIGINAL_CONFIG: LazyLock<Arc<Mutex<PartialConfig>>> =
+    LazyLock::new(|| Arc::new(Mutex::new(PartialConfig::default())));
+
+fn before_each() {
+    configure(ConfigFnOrPartial::Fn(Box::new(|existing_config| {
+        // Grab the existing configuration so we can restore it at the end of the test.
+        let mut original_config = ORIGINAL_CONFIG
+            .lock()
+            .expect("Original config mutex should be acquired.");
+        *original_config = PartialConfig::from(existing_config);
+
+        // Don't change the existing config.
+        PartialConfig::default()
+    })));
+}
+
+fn after_each() {
+    let original_config = ORIGINAL_CONFIG
+        .lock()
+        .expect("Original config mutex should be acquired.");
+
+    configure(ConfigFnOrPartial::Partial((*original_config).clone()));
+}
+
+/// Renders the HTML and returns the suggestion for its first element.
+fn suggest(html: &str, method: Option<Method>) -> Option<Suggestion> {
+    let RenderReturn { container, .. } = render_into_document_div(html);
+
+    let element = container
+        .first_element_child()
+        .expect("Element should exist.")
+        .unchecked_into::<HtmlElement>();
+
+    get_suggested_query(&element, Some(Variant::Get), method)
+}
+
+fn assert_suggestion(suggestion: Option<Suggestion>, query_method: &str, matcher: &str) {
+    let suggestion = suggestion.expect("Suggestion should exist.");
+
+    assert_eq!(query_method, suggestion.query_method);
+    assert_eq!(
+        matcher,
+        match suggestion.query_matcher {
+            SuggestionMatcher::ByRole(ByRoleMatcher::Role(role)) => role.to_string(),
+            SuggestionMatcher::Matcher(Matcher::Regex(regex)) => regex.as_str().to_owned(),
+            SuggestionMatcher::Matcher(matcher) => matcher.to_string(),
+            _ => unreachable!("Suggestions should only match roles and matchers."),
+        }
+    );
+}
+
+#[wasm_bindgen_test]
+fn suggests_role_first() {
+    assert_suggestion(
+        suggest("<button>Submit</button>", None),
+        "get_by_role",
+        "button",
+    );
+}
+
+#[wasm_bindgen_test]
+fn skips_generic_role() {
+    assert_suggestion(
+        suggest("<span role=\"generic\">Hello</span>", None),
+        "get_by_text",
+        "(?i)hello",
+    );
+}
+
+#[wasm_bindgen_test]
+fn suggests_label_text_for_elements_without_role() {
+    assert_suggestion(
+        suggest(
+            "<input id=\"password\" type=\"password\" /><label for=\"password\">Password</label>",
+            None,
+        ),
+        "get_by_label_text",
+        "(?i)password",
+    );
+}
+
+#[wasm_bindgen_test]
+fn suggests_placeholder_text_before_display_value() {
+    assert_suggestion(
+        suggest(
+            "<input type=\"password\" placeholder=\"Secret\" value=\"hunter2\" />",
+            None,
+        ),
+        "get_by_placeholder_text",
+        "(?i)secret",
+    );
+}
+
+#[wasm_bindgen_test]
+fn suggests_display_value() {
+    assert_suggestion(
+        suggest("<input type=\"password\" value=\"Hunter2\" />", None),
+        "get_by_display_value",
+        "(?i)hunter2",
+    );
+}
+
+#[wasm_bindgen_test]
+fn suggests_alt_text_title_and_test_id_in_order() {
+    assert_suggestion(
+        suggest(
+            "<span alt=\"Logo\" title=\"Home\" data-testid=\"logo\"></span>",
+            None,
+        ),
+        "get_by_alt_text",
+        "(?i)logo",
+    );
+    assert_suggestion(
+        suggest("<span title=\"Home\" data-testid=\"logo\"></span>", None),
+        "get_by_title",
+        "(?i)home",
+    );
+    assert_suggestion(
+        suggest("<span data-testid=\"Logo\"></span>", None),
+        "get_by_test_id",
+        "Logo",
+    );
+}
+
+#[wasm_bindgen_test]
+fn respects_requested_method() {
+    assert_suggestion(
+        suggest("<button>Submit</button>", Some(Method::Text)),
+        "get_by_text",
+        "(?i)submit",
+    );
+    assert!(suggest("<button>Submit</button>", Some(Method::TestId)).is_none());
+}
+
+#[wasm_bindgen_test]
+fn does_not_suggest_for_ignored_elements() {
+    assert!(suggest("<style>.hidden { display: none; }</style>", None).is_none());
+}
+
+#[wasm_bindgen_test]
+fn displays_role_suggestion_as_rust() {
+    assert_eq!(
+        "get_by_role(AriaRole::Button, ByRoleOptions::default().name(\"Submit\"))",
+        suggest("<button>Submit</button>", None)
+            .expect("Suggestion should exist.")
+            .to_string()
+    );
+    assert_eq!(
+        "get_by_role(AriaRole::Textbox, ByRoleOptions::default())",
+        suggest("<input type=\"text\" />", None)
+            .expect("Suggestion should exist.")
+            .to_string()
+    );
+}
+
+#[wasm_bindgen_test]
+fn displays_regex_suggestion_as_rust() {
+    assert_eq!(
+        "get_by_text(Regex::new(\"(?i)hello\").expect(\"Regex should be valid.\"), SelectorMatcherOptions::default())",
+        suggest("<span>Hello</span>", None)
+            .expect("Suggestion should exist.")
+            .to_string()
+    );
+    assert_eq!(
+        "get_by_test_id(\"Logo\", MatcherOptions::default())",
+        suggest("<span data-testid=\"Logo\"></span>", None)
+            .expect("Suggestion should exist.")
+            .to_string()
+    );
+}
+
+/// Runs the query with `throw_suggestions` enabled.
+fn with_suggestions<T>(query: impl FnOnce() -> T) -> T {
+    before_each();
+    configure(ConfigFnOrPartial::Partial(
+        PartialConfig::default().throw_suggestions(true),
+    ));
+
+    let result = query();
+
+    after_each();
+
+    result
+}
+
+fn assert_suggestion_error<T>(result: Result<T, QueryError>, suggestion: &str) {
+    match result {
+        Err(QueryError::Element(message)) => assert!(
+            message.starts_with(&format!(
+                "A better query is available, try this: {suggestion}"
+            )),
+            "Unexpected message: {message}"
+        ),
+        _ => panic!("Query should fail with a suggestion."),
+    }
+}
+
+#[wasm_bindgen_test]
+fn all_by_queries_suggest_when_all_elements_agree() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(
+        "<button data-testid=\"submit\">Submit</button><button data-testid=\"submit\">Submit</button>",
+    );
+
+    assert_suggestion_error(
+        with_suggestions(|| {
+            container_queries.query_all_by_test_id("submit", MatcherOptions::default())
+        }),
+        "query_all_by_role(AriaRole::Button, ByRoleOptions::default().name(\"Submit\"))",
+    );
+    assert_suggestion_error(
+        with_suggestions(|| {
+            container_queries.get_all_by_test_id("submit", MatcherOptions::default())
+        }),
+        "get_all_by_role(AriaRole::Button, ByRoleOptions::default().name(\"Submit\"))",
+    );
+}
+
+#[wasm_bindgen_test]
+fn all_by_queries_do_not_suggest_when_elements_differ() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div(
+        "<button data-testid=\"action\">Submit</button><button data-testid=\"action\">Cancel</button>",
+    );
+
+    assert_eq!(
+        2,
+        with_suggestions(|| {
+            container_queries.get_all_by_test_id("action", MatcherOptions::default())
+        })
+        .expect("Get should succeed.")
+        .len()
+    );
+}
+
+#[wasm_bindgen_test]
+fn all_by_queries_do_not_suggest_the_same_method() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div("<button>Submit</button><button>Submit</button>");
+
+    assert_eq!(
+        2,
+        with_suggestions(|| {
+            container_queries.get_all_by_role(AriaRole::Button, ByRoleOptions::default())
+        })
+        .expect("Get should succeed.")
+        .len()
+    );
+}
+
+#[wasm_bindgen_test]
+fn suggest_option_overrides_throw_suggestions() {
+    let RenderReturn {
+        container_queries, ..
+    } = render_into_document_div("<button data-testid=\"submit\">Submit</button>");
+
+    assert!(
+        with_suggestions(|| {
+            container_queries.get_by_test_id("submit", MatcherOptions::default().suggest(false))
+        })
+        .is_ok()
+    );
+    assert!(
+        with_suggestions(|| {
+            container_queries
+                .query_all_by_test_id("submit", MatcherOptions::default().suggest(false))
+        })
+        .is_ok()
+    );
+
+    assert_suggestion_error(
+        container_queries.get_by_test_id("submit", MatcherOptions::default().suggest(true)),
+        "get_by_role(AriaRole::Button, ByRoleOptions::default().name(\"Submit\"))",
+    );
+    assert_suggestion_error(
+        container_queries.query_all_by_test_id("submit", MatcherOptions::default().suggest(true)),
+        "query_all_by_role(AriaRole::Button, ByRoleOptions::default().name(\"Submit\"))",
+    );
+}
+
+#[wasm_bindgen_test]
+fn role_suggestions_for_inaccessible_elements_include_hidden() {
+    let suggestion = suggest("<button aria-hidden=\"true\">Submit</button>", None)
+        .expect("Suggestion should exist.");
+
+    assert!(matches!(
+        &suggestion.query_options,
+        SuggestionOptions::ByRole(options) if options.hidden == Some(true)
+    ));
+    assert_eq!(
+        Some(
+            "Element is inaccessible. This means that the element and all its children are invisible to screen \
+            readers. If it is hidden with `aria-hidden`, `hidden` or CSS, make sure this is intended."
+                .to_owned()
+        ),
+        suggestion.warning
+    );
+    assert!(
+        suggestion
+            .to_string()
+            .starts_with("get_by_role(AriaRole::Button, ByRoleOptions::default()")
+    );
+    assert!(suggestion.to_string().contains(".hidden(true)"));
+}
+
+#[wasm_bindgen_test]
+fn role_suggestions_for_accessible_elements_have_no_warning() {
+    let suggestion = suggest("<button>Submit</button>", None).expect("Suggestion should exist.");
+
+    assert!(matches!(
+        &suggestion.query_options,
+        SuggestionOptions::ByRole(options) if options.hidden.is_none()
+    ));
+    assert!(suggestion.warning.is_none());
+}
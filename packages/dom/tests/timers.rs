@@ -0,0 +1,250 @@
+#![cfg(target_arch = "wasm32")]
+
+mod helpers;
+
+use std::{cell::RefCell, rc::Rc};
+
+use js_sys::Date;
+use testing_library_dom::{
+    QueryError, SelectorMatcherOptions, WaitForOptions, advance_timers_by, fake_timers_are_enabled,
+    run_all_timers, use_fake_timers, use_real_timers, wait_for,
+};
+use wasm_bindgen::{JsCast, closure::Closure};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::window;
+
+use self::helpers::test_utils::{RenderReturn, render};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn set_timeout(callback: impl FnOnce() + 'static, timeout: i32) -> i32 {
+    window()
+        .expect("Window should exist.")
+        .set_timeout_with_callback_and_timeout_and_arguments_0(
+            Closure::once_into_js(callback).unchecked_ref(),
+            timeout,
+        )
+        .expect("Timeout should be set.")
+}
+
+fn set_interval(callback: impl FnMut() + 'static, timeout: i32) -> i32 {
+    window()
+        .expect("Window should exist.")
+        .set_interval_with_callback_and_timeout_and_arguments_0(
+            Closure::<dyn FnMut()>::new(callback)
+                .into_js_value()
+                .unchecked_ref(),
+            timeout,
+        )
+        .expect("Interval should be set.")
+}
+
+fn record(calls: &Rc<RefCell<Vec<&'static str>>>, name: &'static str) -> impl FnOnce() + 'static {
+    let calls = calls.clone();
+    move || calls.borrow_mut().push(name)
+}
+
+#[wasm_bindgen_test]
+fn advance_timers_by_runs_due_timers_in_order() {
+    use_fake_timers();
+
+    let calls = Rc::new(RefCell::new(vec![]));
+    set_timeout(record(&calls, "200"), 200);
+    set_timeout(record(&calls, "100"), 100);
+    set_timeout(record(&calls, "300"), 300);
+
+    advance_timers_by(99);
+    assert!(calls.borrow().is_empty());
+
+    advance_timers_by(101);
+    assert_eq!(vec!["100", "200"], *calls.borrow());
+
+    advance_timers_by(100);
+    assert_eq!(vec!["100", "200", "300"], *calls.borrow());
+
+    use_real_timers();
+}
+
+#[wasm_bindgen_test]
+fn advance_timers_by_advances_date_now() {
+    use_fake_timers();
+
+    let start = Date::now();
+    advance_timers_by(1000);
+    assert_eq!(start + 1000.0, Date::now());
+
+    use_real_timers();
+}
+
+#[wasm_bindgen_test]
+fn clear_timeout_cancels_fake_timers() {
+    use_fake_timers();
+
+    let calls = Rc::new(RefCell::new(vec![]));
+    let handle = set_timeout(record(&calls, "cancelled"), 100);
+    window()
+        .expect("Window should exist.")
+        .clear_timeout_with_handle(handle);
+
+    advance_timers_by(200);
+    assert!(calls.borrow().is_empty());
+
+    use_real_timers();
+}
+
+#[wasm_bindgen_test]
+fn set_interval_repeats_until_cleared() {
+    use_fake_timers();
+
+    let calls = Rc::new(RefCell::new(0));
+    let handle = set_interval(
+        {
+            let calls = calls.clone();
+            move || *calls.borrow_mut() += 1
+        },
+        100,
+    );
+
+    advance_timers_by(350);
+    assert_eq!(3, *calls.borrow());
+
+    window()
+        .expect("Window should exist.")
+        .clear_interval_with_handle(handle);
+    advance_timers_by(350);
+    assert_eq!(3, *calls.borrow());
+
+    use_real_timers();
+}
+
+#[wasm_bindgen_test]
+fn request_animation_frame_runs_on_the_next_frame() {
+    use_fake_timers();
+
+    let calls = Rc::new(RefCell::new(vec![]));
+    window()
+        .expect("Window should exist.")
+        .request_animation_frame(Closure::once_into_js(record(&calls, "frame")).unchecked_ref())
+        .expect("Animation frame should be requested.");
+
+    advance_timers_by(1);
+    assert!(calls.borrow().is_empty());
+
+    advance_timers_by(20);
+    assert_eq!(vec!["frame"], *calls.borrow());
+
+    use_real_timers();
+}
+
+#[wasm_bindgen_test]
+fn run_all_timers_runs_timers_scheduled_by_timers() {
+    use_fake_timers();
+
+    let calls = Rc::new(RefCell::new(vec![]));
+    set_timeout(
+        {
+            let calls = calls.clone();
+            move || {
+                calls.borrow_mut().push("outer");
+                set_timeout(record(&calls, "inner"), 1000);
+            }
+        },
+        1000,
+    );
+
+    run_all_timers();
+    assert_eq!(vec!["outer", "inner"], *calls.borrow());
+
+    use_real_timers();
+}
+
+#[wasm_bindgen_test]
+fn use_real_timers_restores_the_original_timers() {
+    let window = window().expect("Window should exist.");
+    let original =
+        js_sys::Reflect::get(&window, &"setTimeout".into()).expect("Property should be read.");
+
+    use_fake_timers();
+    assert!(fake_timers_are_enabled());
+    assert_ne!(
+        original,
+        js_sys::Reflect::get(&window, &"setTimeout".into()).expect("Property should be read.")
+    );
+
+    use_real_timers();
+    assert!(!fake_timers_are_enabled());
+    assert_eq!(
+        original,
+        js_sys::Reflect::get(&window, &"setTimeout".into()).expect("Property should be read.")
+    );
+}
+
+#[wasm_bindgen_test]
+async fn find_by_advances_fake_timers() {
+    use_fake_timers();
+
+    let RenderReturn {
+        container,
+        container_queries,
+        ..
+    } = render("<div />", None);
+
+    // Real time does not pass while the fake timers are enabled, so this only runs if `find_by` advances them.
+    set_timeout(move || container.set_inner_html("<span>Loaded</span>"), 500);
+
+    let start = Date::now();
+    let element = container_queries
+        .find_by_text(
+            "Loaded",
+            SelectorMatcherOptions::default(),
+            WaitForOptions::default(),
+        )
+        .await;
+
+    assert_eq!(
+        Some("SPAN".to_owned()),
+        element.ok().map(|element| element.tag_name())
+    );
+    assert_eq!(start + 500.0, Date::now());
+
+    use_real_timers();
+}
+
+#[wasm_bindgen_test]
+async fn wait_for_times_out_in_fake_time() {
+    use_fake_timers();
+
+    let start = Date::now();
+    let result: Result<(), QueryError> = wait_for(
+        Box::new(|| Err(QueryError::Element("always throws".to_owned()))),
+        WaitForOptions::default()
+            .timeout(5000)
+            .on_timeout(Rc::new(|error| error)),
+    )
+    .await;
+
+    assert_eq!(Err(QueryError::Element("always throws".to_owned())), result);
+    assert_eq!(start + 5000.0, Date::now());
+
+    use_real_timers();
+}
+
+#[wasm_bindgen_test]
+async fn wait_for_times_out_in_fake_time_with_zero_interval() {
+    use_fake_timers();
+
+    let start = Date::now();
+    let result: Result<(), QueryError> = wait_for(
+        Box::new(|| Err(QueryError::Element("always throws".to_owned()))),
+        WaitForOptions::default()
+            .timeout(100)
+            .interval(0)
+            .on_timeout(Rc::new(|error| error)),
+    )
+    .await;
+
+    assert_eq!(Err(QueryError::Element("always throws".to_owned())), result);
+    assert_eq!(start + 100.0, Date::now());
+
+    use_real_timers();
+}
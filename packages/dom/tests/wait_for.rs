@@ -0,0 +1,494 @@
+#![cfg(target_arch = "wasm32")]
+
+mod helpers;
+
+use std::{
+    cell::Cell,
+    rc::Rc,
+    sync::{Arc, LazyLock, Mutex},
+};
+
+use indoc::indoc;
+use js_sys::{Date, Promise};
+use testing_library_dom::{
+    ConfigFnOrPartial, PartialConfig, QueryError, SelectorMatcherOptions, WaitForOptions,
+    configure, wait_for, wait_for_async,
+};
+use wasm_bindgen::{JsCast, closure::Closure};
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::{AbortController, window};
+
+use self::helpers::test_utils::{RenderReturn, render};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+static ORIGINAL_CONFIG: LazyLock<Arc<Mutex<PartialConfig>>> =
+    LazyLock::new(|| Arc::new(Mutex::new(PartialConfig::default())));
+
+fn before_each() {
+    configure(ConfigFnOrPartial::Fn(Box::new(|existing_config| {
+        // Grab the existing configuration so we can restore it at the end of the test.
+        let mut original_config = ORIGINAL_CONFIG
+            .lock()
+            .expect("Original config mutex should be acquired.");
+        *original_config = PartialConfig::from(existing_config);
+
+        // Don't change the existing config.
+        PartialConfig::default()
+    })));
+}
+
+fn after_each() {
+    let original_config = ORIGINAL_CONFIG
+        .lock()
+        .expect("Original config mutex should be acquired.");
+
+    configure(ConfigFnOrPartial::Partial((*original_config).clone()));
+}
+
+fn set_timeout(callback: impl FnOnce() + 'static, timeout: i32) {
+    window()
+        .expect("Window should exist.")
+        .set_timeout_with_callback_and_timeout_and_arguments_0(
+            Closure::once_into_js(callback).unchecked_ref(),
+            timeout,
+        )
+        .expect("Timeout should be set.");
+}
+
+async fn sleep(timeout: i32) {
+    JsFuture::from(Promise::new(&mut |resolve, _reject| {
+        window()
+            .expect("Window should exist.")
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, timeout)
+            .expect("Timeout should be set.");
+    }))
+    .await
+    .expect("Promise should resolve.");
+}
+
+#[wasm_bindgen_test]
+async fn wait_for_resolves_immediately_if_the_callback_succeeds() {
+    let calls = Rc::new(Cell::new(0));
+
+    let result = wait_for(
+        {
+            let calls = calls.clone();
+            Box::new(move || {
+                calls.set(calls.get() + 1);
+                Ok(42)
+            })
+        },
+        WaitForOptions::default(),
+    )
+    .await;
+
+    assert_eq!(Ok(42), result);
+    assert_eq!(1, calls.get());
+}
+
+#[wasm_bindgen_test]
+async fn wait_for_retries_until_the_callback_succeeds() {
+    let calls = Rc::new(Cell::new(0));
+
+    let result = wait_for(
+        {
+            let calls = calls.clone();
+            Box::new(move || {
+                calls.set(calls.get() + 1);
+                if calls.get() < 3 {
+                    Err(QueryError::Element("Not yet.".to_owned()))
+                } else {
+                    Ok(calls.get())
+                }
+            })
+        },
+        WaitForOptions::default().interval(10),
+    )
+    .await;
+
+    assert_eq!(Ok(3), result);
+}
+
+#[wasm_bindgen_test]
+async fn wait_for_returns_the_last_error_on_timeout() {
+    let calls = Rc::new(Cell::new(0));
+
+    let result: Result<(), QueryError> = wait_for(
+        {
+            let calls = calls.clone();
+            Box::new(move || {
+                calls.set(calls.get() + 1);
+                Err(QueryError::Element(format!("Attempt {}", calls.get())))
+            })
+        },
+        WaitForOptions::default()
+            .timeout(100)
+            .interval(10)
+            .on_timeout(Rc::new(|error| error)),
+    )
+    .await;
+
+    assert!(calls.get() > 1);
+    assert_eq!(
+        Err(QueryError::Element(format!("Attempt {}", calls.get()))),
+        result
+    );
+}
+
+#[wasm_bindgen_test]
+async fn find_by_resolves_once_the_element_is_rendered() {
+    let RenderReturn {
+        container,
+        container_queries,
+        ..
+    } = render("<div />", None);
+
+    set_timeout(move || container.set_inner_html("<span>Loaded</span>"), 50);
+
+    let element = container_queries
+        .find_by_text(
+            "Loaded",
+            SelectorMatcherOptions::default(),
+            WaitForOptions::default(),
+        )
+        .await
+        .expect("Find should succeed.");
+
+    assert_eq!("SPAN", element.tag_name());
+}
+
+#[wasm_bindgen_test]
+async fn find_by_rechecks_on_dom_mutations_before_the_next_interval() {
+    let RenderReturn {
+        container,
+        container_queries,
+        ..
+    } = render("<div />", None);
+
+    set_timeout(move || container.set_inner_html("<span>Loaded</span>"), 50);
+
+    // The interval is longer than the timeout, so without the mutation observer the check would only be repeated
+    // once the timeout is reached.
+    let start = Date::now();
+    let element = container_queries
+        .find_by_text(
+            "Loaded",
+            SelectorMatcherOptions::default(),
+            WaitForOptions::default().timeout(1000).interval(5000),
+        )
+        .await
+        .expect("Find should succeed.");
+
+    assert_eq!("SPAN", element.tag_name());
+    assert!(Date::now() - start < 500.0);
+}
+
+#[wasm_bindgen_test]
+async fn wait_for_adds_the_dom_to_timeout_errors() {
+    let RenderReturn { container, .. } = render("<div id=\"pretty\">how pretty</div>", None);
+
+    let result: Result<(), QueryError> = wait_for(
+        Box::new(|| Err(QueryError::Element("always throws".to_owned()))),
+        WaitForOptions::default().container(container).timeout(1),
+    )
+    .await;
+
+    assert_eq!(
+        Err(QueryError::Element(
+            indoc! {"
+            always throws
+
+            Ignored nodes: comments, script, style
+            <div>
+              <div
+                id=\"pretty\"
+              >
+                how pretty
+              </div>
+            </div>"}
+            .to_owned()
+        )),
+        result
+    );
+}
+
+#[wasm_bindgen_test]
+async fn wait_for_passes_non_element_errors_through_on_timeout() {
+    let result: Result<(), QueryError> = wait_for(
+        Box::new(|| Err(QueryError::Unsupported("not supported".to_owned()))),
+        WaitForOptions::default().timeout(1),
+    )
+    .await;
+
+    assert_eq!(
+        Err(QueryError::Unsupported("not supported".to_owned())),
+        result
+    );
+}
+
+#[wasm_bindgen_test]
+async fn find_by_prints_the_dom_once_on_timeout() {
+    let RenderReturn {
+        container_queries, ..
+    } = render("<div>Loading</div>", None);
+
+    let result = container_queries
+        .find_by_text(
+            "Loaded",
+            SelectorMatcherOptions::default(),
+            WaitForOptions::default().timeout(50),
+        )
+        .await;
+
+    assert_eq!(
+        Err(QueryError::Element(
+            indoc! {"
+            Unable to find an element with the text: Loaded. This could be because the text is broken up by multiple elements. In this case, you can provide a function for your text matcher to make your matcher more flexible.
+
+            Ignored nodes: comments, script, style
+            <div>
+              <div>
+                Loading
+              </div>
+            </div>"}
+            .to_owned()
+        )),
+        result
+    );
+}
+
+#[wasm_bindgen_test]
+async fn find_by_uses_custom_element_errors_once_on_timeout() {
+    before_each();
+
+    configure(ConfigFnOrPartial::Partial(
+        PartialConfig::default().get_element_error(Arc::new(|message, _container| {
+            QueryError::Element(format!("Custom: {}", message.unwrap_or_default()))
+        })),
+    ));
+
+    let RenderReturn {
+        container_queries, ..
+    } = render("<div>Loading</div>", None);
+
+    let result = container_queries
+        .find_by_text(
+            "Loaded",
+            SelectorMatcherOptions::default(),
+            WaitForOptions::default().timeout(50),
+        )
+        .await;
+
+    after_each();
+
+    assert_eq!(
+        Err(QueryError::Element(
+            "Custom: Unable to find an element with the text: Loaded. This could be because the text is broken up by \
+            multiple elements. In this case, you can provide a function for your text matcher to make your matcher \
+            more flexible."
+                .to_owned()
+        )),
+        result
+    );
+}
+
+#[wasm_bindgen_test]
+async fn wait_for_passes_the_last_error_to_on_timeout() {
+    let result: Result<(), QueryError> = wait_for(
+        Box::new(|| Err(QueryError::Element("always throws".to_owned()))),
+        WaitForOptions::default()
+            .timeout(1)
+            .on_timeout(Rc::new(|error| {
+                QueryError::Element(format!("Custom timeout: {error}"))
+            })),
+    )
+    .await;
+
+    assert_eq!(
+        Err(QueryError::Element(
+            "Custom timeout: always throws".to_owned()
+        )),
+        result
+    );
+}
+
+#[wasm_bindgen_test]
+async fn wait_for_async_can_await_inside_the_callback() {
+    let RenderReturn {
+        container,
+        container_queries,
+        ..
+    } = render("<div />", None);
+    let container_queries = Rc::new(container_queries);
+
+    set_timeout(move || container.set_inner_html("<span>Loaded</span>"), 50);
+
+    let text = wait_for_async(
+        Box::new(move || {
+            let container_queries = container_queries.clone();
+
+            async move {
+                let element = container_queries
+                    .find_by_text(
+                        "Loaded",
+                        SelectorMatcherOptions::default(),
+                        WaitForOptions::default().timeout(10),
+                    )
+                    .await?;
+
+                Ok(element.text_content())
+            }
+        }),
+        WaitForOptions::default(),
+    )
+    .await;
+
+    assert_eq!(Ok(Some("Loaded".to_owned())), text);
+}
+
+#[wasm_bindgen_test]
+async fn wait_for_async_does_not_start_overlapping_attempts() {
+    let calls = Rc::new(Cell::new(0));
+    let running = Rc::new(Cell::new(false));
+
+    let result = wait_for_async(
+        {
+            let calls = calls.clone();
+            let running = running.clone();
+
+            Box::new(move || {
+                let calls = calls.clone();
+                let running = running.clone();
+
+                async move {
+                    assert!(!running.get(), "Attempts should not overlap.");
+                    running.set(true);
+                    calls.set(calls.get() + 1);
+
+                    // Slower than the interval.
+                    sleep(50).await;
+
+                    running.set(false);
+                    if calls.get() < 3 {
+                        Err(QueryError::Element("Not yet.".to_owned()))
+                    } else {
+                        Ok(calls.get())
+                    }
+                }
+            })
+        },
+        WaitForOptions::default().interval(10),
+    )
+    .await;
+
+    assert_eq!(Ok(3), result);
+}
+
+#[wasm_bindgen_test]
+async fn wait_for_async_times_out_while_an_attempt_is_pending() {
+    let result: Result<(), QueryError> = wait_for_async(
+        Box::new(|| async {
+            sleep(1000).await;
+            Ok(())
+        }),
+        WaitForOptions::default()
+            .timeout(50)
+            .on_timeout(Rc::new(|error| error)),
+    )
+    .await;
+
+    assert_eq!(
+        Err(QueryError::Element("Timed out in `wait_for`.".to_owned())),
+        result
+    );
+}
+
+#[wasm_bindgen_test]
+async fn wait_for_fails_immediately_if_the_signal_is_already_aborted() {
+    let controller = AbortController::new().expect("Abort controller should be created.");
+    controller.abort_with_reason(&"cancelled".into());
+
+    let calls = Rc::new(Cell::new(0));
+    let result: Result<(), QueryError> = wait_for(
+        {
+            let calls = calls.clone();
+            Box::new(move || {
+                calls.set(calls.get() + 1);
+                Ok(())
+            })
+        },
+        WaitForOptions::default().signal(controller.signal()),
+    )
+    .await;
+
+    assert_eq!(Err(QueryError::Aborted("cancelled".into())), result);
+    assert_eq!(0, calls.get());
+}
+
+#[wasm_bindgen_test]
+async fn wait_for_stops_checking_when_aborted() {
+    let RenderReturn { container, .. } = render("<div />", None);
+
+    let controller = AbortController::new().expect("Abort controller should be created.");
+    set_timeout(
+        {
+            let controller = controller.clone();
+            move || controller.abort_with_reason(&"cancelled".into())
+        },
+        50,
+    );
+
+    let calls = Rc::new(Cell::new(0));
+    let start = Date::now();
+    let result: Result<(), QueryError> = wait_for(
+        {
+            let calls = calls.clone();
+            Box::new(move || {
+                calls.set(calls.get() + 1);
+                Err(QueryError::Element("not yet".to_owned()))
+            })
+        },
+        WaitForOptions::default()
+            .timeout(5000)
+            .interval(10)
+            .signal(controller.signal()),
+    )
+    .await;
+
+    assert_eq!(Err(QueryError::Aborted("cancelled".into())), result);
+    assert!(Date::now() - start < 1000.0);
+
+    // Neither the interval nor the mutation observer run the callback after aborting.
+    let calls_after_abort = calls.get();
+    container.set_inner_html("<span>Mutated</span>");
+    sleep(50).await;
+    assert_eq!(calls_after_abort, calls.get());
+}
+
+#[wasm_bindgen_test]
+async fn find_by_can_be_aborted() {
+    let RenderReturn {
+        container_queries, ..
+    } = render("<div />", None);
+
+    let controller = AbortController::new().expect("Abort controller should be created.");
+    set_timeout(
+        {
+            let controller = controller.clone();
+            move || controller.abort()
+        },
+        20,
+    );
+
+    let result = container_queries
+        .find_by_text(
+            "Never",
+            SelectorMatcherOptions::default(),
+            WaitForOptions::default().signal(controller.signal()),
+        )
+        .await;
+
+    assert!(matches!(result, Err(QueryError::Aborted(_))));
+}
@@ -0,0 +1,247 @@
+#![cfg(target_arch = "wasm32")]
+
+mod helpers;
+
+use std::{cell::Cell, rc::Rc};
+
+use indoc::indoc;
+use testing_library_dom::{
+    MatcherOptions, QueryError, WaitForElementToBeRemovedFn, WaitForOptions, get_all_by_test_id,
+    wait_for_element_to_be_removed,
+};
+use wasm_bindgen::{JsCast, closure::Closure};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::{HtmlElement, window};
+
+use self::helpers::test_utils::{RenderReturn, document, render};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn set_timeout(callback: impl FnOnce() + 'static, timeout: i32) {
+    window()
+        .expect("Window should exist.")
+        .set_timeout_with_callback_and_timeout_and_arguments_0(
+            Closure::once_into_js(callback).unchecked_ref(),
+            timeout,
+        )
+        .expect("Timeout should be set.");
+}
+
+#[wasm_bindgen_test]
+async fn resolves_on_mutation_only_when_the_element_is_removed() {
+    let RenderReturn {
+        container_queries, ..
+    } = render(
+        "<div data-testid=\"div\"></div><div data-testid=\"div\"></div>",
+        None,
+    );
+    let divs = container_queries
+        .get_all_by_test_id("div", MatcherOptions::default())
+        .expect("Get should succeed.");
+
+    // First mutation
+    set_timeout(
+        {
+            let divs = divs.clone();
+            move || {
+                for div in divs {
+                    div.set_attribute("id", "mutated")
+                        .expect("Attribute should be set.");
+                }
+            }
+        },
+        0,
+    );
+    // Removal
+    set_timeout(
+        {
+            let divs = divs.clone();
+            move || {
+                for div in divs {
+                    div.remove();
+                }
+            }
+        },
+        100,
+    );
+
+    container_queries
+        .wait_for_element_to_be_removed(divs, WaitForOptions::default().timeout(200))
+        .await
+        .expect("Elements should be removed.");
+}
+
+#[wasm_bindgen_test]
+async fn resolves_on_mutation_if_callback_errors() {
+    let RenderReturn {
+        container,
+        container_queries,
+        ..
+    } = render("<div data-testid=\"div\"></div>", None);
+    let div = container_queries
+        .get_by_test_id("div", MatcherOptions::default())
+        .expect("Get should succeed.");
+
+    set_timeout(move || div.remove(), 50);
+
+    let callback: Box<WaitForElementToBeRemovedFn> =
+        Box::new(move || get_all_by_test_id(&container, "div", MatcherOptions::default()));
+    wait_for_element_to_be_removed(callback, WaitForOptions::default().timeout(200))
+        .await
+        .expect("Elements should be removed.");
+}
+
+#[wasm_bindgen_test]
+async fn accepts_closures_as_callback() {
+    let RenderReturn {
+        container,
+        container_queries,
+        ..
+    } = render("<div data-testid=\"div\"></div>", None);
+    let div = container_queries
+        .get_by_test_id("div", MatcherOptions::default())
+        .expect("Get should succeed.");
+
+    set_timeout(move || div.remove(), 50);
+
+    wait_for_element_to_be_removed(
+        move || get_all_by_test_id(&container, "div", MatcherOptions::default()),
+        WaitForOptions::default().timeout(200),
+    )
+    .await
+    .expect("Elements should be removed.");
+}
+
+#[wasm_bindgen_test]
+async fn requires_an_element_to_exist_first() {
+    let result =
+        wait_for_element_to_be_removed(Vec::<HtmlElement>::new(), WaitForOptions::default()).await;
+
+    assert_eq!(
+        Err(QueryError::Element(
+            "The element(s) given to `wait_for_element_to_be_removed` are already removed. \
+            `wait_for_element_to_be_removed` requires that the element(s) exist(s) before waiting for removal."
+                .to_owned()
+        )),
+        result
+    );
+}
+
+#[wasm_bindgen_test]
+async fn requires_an_unempty_array_of_elements_to_exist_first_from_callback() {
+    let callback: Box<WaitForElementToBeRemovedFn> = Box::new(|| Ok(vec![]));
+    let result = wait_for_element_to_be_removed(callback, WaitForOptions::default()).await;
+
+    assert_eq!(
+        Err(QueryError::Element(
+            "The element(s) given to `wait_for_element_to_be_removed` are already removed. \
+            `wait_for_element_to_be_removed` requires that the element(s) exist(s) before waiting for removal."
+                .to_owned()
+        )),
+        result
+    );
+}
+
+#[wasm_bindgen_test]
+async fn requires_the_element_to_have_a_parent() {
+    let element = document()
+        .create_element("div")
+        .expect("Element should be created.")
+        .unchecked_into::<HtmlElement>();
+
+    let result = wait_for_element_to_be_removed(element, WaitForOptions::default()).await;
+
+    assert_eq!(
+        Err(QueryError::Element(
+            "The element(s) given to `wait_for_element_to_be_removed` are already removed. \
+            `wait_for_element_to_be_removed` requires that the element(s) exist(s) before waiting for removal."
+                .to_owned()
+        )),
+        result
+    );
+}
+
+#[wasm_bindgen_test]
+async fn after_successful_removal_fulfills_promise_with_empty_value() {
+    let RenderReturn {
+        container_queries, ..
+    } = render("<div data-testid=\"div\"></div>", None);
+    let div = container_queries
+        .get_by_test_id("div", MatcherOptions::default())
+        .expect("Get should succeed.");
+
+    set_timeout(
+        {
+            let div = div.clone();
+            move || div.remove()
+        },
+        0,
+    );
+
+    assert_eq!(
+        Ok(()),
+        container_queries
+            .wait_for_element_to_be_removed(div, WaitForOptions::default().timeout(100))
+            .await
+    );
+}
+
+#[wasm_bindgen_test]
+async fn rethrows_non_element_errors() {
+    let RenderReturn {
+        container_queries, ..
+    } = render("<div data-testid=\"div\"></div>", None);
+    let div = container_queries
+        .get_by_test_id("div", MatcherOptions::default())
+        .expect("Get should succeed.");
+
+    let calls = Rc::new(Cell::new(0));
+    let callback: Box<WaitForElementToBeRemovedFn> = Box::new({
+        let calls = calls.clone();
+        move || {
+            calls.set(calls.get() + 1);
+            if calls.get() == 1 {
+                Ok(vec![div.clone()])
+            } else {
+                Err(QueryError::Configuration(
+                    "Something went wrong.".to_owned(),
+                ))
+            }
+        }
+    });
+
+    assert_eq!(
+        Err(QueryError::Configuration(
+            "Something went wrong.".to_owned()
+        )),
+        wait_for_element_to_be_removed(callback, WaitForOptions::default().timeout(100)).await
+    );
+}
+
+#[wasm_bindgen_test]
+async fn times_out_if_the_element_is_not_removed() {
+    let RenderReturn {
+        container_queries, ..
+    } = render("<div data-testid=\"div\"></div>", None);
+    let div = container_queries
+        .get_by_test_id("div", MatcherOptions::default())
+        .expect("Get should succeed.");
+
+    assert_eq!(
+        Err(QueryError::Element(
+            indoc! {"
+            Timed out in `wait_for_element_to_be_removed`.
+
+            Ignored nodes: comments, script, style
+            <div>
+              <div
+                data-testid=\"div\"
+              />
+            </div>"}
+            .to_owned()
+        )),
+        container_queries
+            .wait_for_element_to_be_removed(div, WaitForOptions::default().timeout(100))
+            .await
+    );
+}